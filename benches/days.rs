@@ -0,0 +1,106 @@
+use advent_2025::Solution;
+use advent_2025::day01::Day01;
+use advent_2025::day02::Day02;
+use advent_2025::day03::Day03;
+use advent_2025::day04::Day04;
+use advent_2025::day05::Day05;
+use advent_2025::day05::condense_ranges;
+use advent_2025::day05::prob1::{Lookup, count_fresh};
+use advent_2025::day06::Day06;
+use advent_2025::day07::Day07;
+use advent_2025::day08::Day08;
+use advent_2025::day09::Day09;
+use advent_2025::day10::Day10;
+use advent_2025::day11::Day11;
+use advent_2025::day12::Day12;
+use advent_2025::input::default_path;
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+/// Benchmark parsing the committed input for a day, and each of its parts separately from the
+/// parse by handing every iteration a fresh clone of the parsed input
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, parts: u8) {
+    let input = std::fs::read_to_string(default_path(day)).expect("committed input exists");
+    let parsed = S::parse(&input).expect("committed input parses");
+    let mut group = c.benchmark_group(format!("day{:02}", day));
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| parsed.clone(), S::part1, BatchSize::LargeInput)
+    });
+    if parts > 1 {
+        group.bench_function("part2", |b| {
+            b.iter_batched(|| parsed.clone(), S::part2, BatchSize::LargeInput)
+        });
+    }
+
+    group.finish();
+}
+
+/// A cheap deterministic stream of pseudo-random numbers, so that the benchmarks don't need a
+/// random number crate
+fn xorshift(mut state: u64) -> impl Iterator<Item = u64> {
+    std::iter::repeat_with(move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    })
+}
+
+/// Benchmark both of day 5's ways of checking available IDs, across a spread of numbers of
+/// IDs and ranges, to check where `Lookup::choose` switches between them
+fn bench_day05_lookups(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day05_lookups");
+    group.sample_size(10);
+    for ranges_len in [100, 10_000, 1_000_000] {
+        // ranges spread out over the IDs, so that about half of the IDs are fresh
+        let fresh = condense_ranges(
+            (0..ranges_len as u64)
+                .zip(xorshift(1))
+                .map(|(i, noise)| (i * 1000, i * 1000 + 250 + noise % 500))
+                .collect(),
+        );
+        for available_len in [100, 10_000, 1_000_000] {
+            let available: Vec<u64> = xorshift(2)
+                .map(|id| id % (ranges_len as u64 * 1000))
+                .take(available_len)
+                .collect();
+            for lookup in [Lookup::BinarySearch, Lookup::Sweep] {
+                group.bench_function(
+                    BenchmarkId::new(
+                        format!("{:?}", lookup),
+                        format!("{}ids_{}ranges", available_len, ranges_len),
+                    ),
+                    |b| {
+                        b.iter_batched(
+                            || available.clone(),
+                            |available| count_fresh(&fresh, available, lookup),
+                            BatchSize::LargeInput,
+                        )
+                    },
+                );
+            }
+        }
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<Day01>(c, 1, 2);
+    bench_day::<Day02>(c, 2, 2);
+    bench_day::<Day03>(c, 3, 2);
+    bench_day::<Day04>(c, 4, 2);
+    bench_day::<Day05>(c, 5, 2);
+    bench_day::<Day06>(c, 6, 2);
+    bench_day::<Day07>(c, 7, 2);
+    bench_day::<Day08>(c, 8, 2);
+    bench_day::<Day09>(c, 9, 1);
+    bench_day::<Day10>(c, 10, 1);
+    bench_day::<Day11>(c, 11, 2);
+    bench_day::<Day12>(c, 12, 1);
+}
+
+criterion_group!(benches, days, bench_day05_lookups);
+criterion_main!(benches);
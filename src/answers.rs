@@ -0,0 +1,139 @@
+use crate::input::resolve;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// The known answers for the committed puzzle inputs, keyed by day and part.  These are read from
+/// `answers.toml`, which has a table per day holding a key per part:
+///
+/// ```toml
+/// [day01]
+/// part1 = 1158
+/// part2 = 6860
+/// ```
+#[derive(PartialEq, Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u8, u8), String>,
+}
+
+/// The outcome of checking a computed answer against the known answer
+#[derive(PartialEq, Debug)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    /// The path of the answers file in the crate root
+    pub fn default_path() -> PathBuf {
+        resolve(Path::new("answers.toml"))
+    }
+
+    /// Parse the contents of an answers file
+    pub fn parse(contents: &str) -> Result<Answers, String> {
+        let table: toml::Table = contents.parse().map_err(|err| format!("{}", err))?;
+        let mut answers: HashMap<(u8, u8), String> = HashMap::new();
+
+        for (day_key, parts) in table.iter() {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| format!("expected a table named dayNN, found `{}`", day_key))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("expected `{}` to be a table", day_key))?;
+
+            for (part_key, answer) in parts.iter() {
+                let part = part_key
+                    .strip_prefix("part")
+                    .and_then(|part| part.parse::<u8>().ok())
+                    .ok_or_else(|| {
+                        format!(
+                            "expected a key named partN in `{}`, found `{}`",
+                            day_key, part_key
+                        )
+                    })?;
+                let answer = match answer {
+                    toml::Value::Integer(num) => num.to_string(),
+                    toml::Value::String(text) => text.clone(),
+                    _ => {
+                        return Err(format!(
+                            "expected `{}.{}` to be an integer or string",
+                            day_key, part_key
+                        ));
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    /// Read and parse the answers file at `path`
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| format!("unable to read {}: {}", path.display(), err))?;
+        Answers::parse(&contents).map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    /// The known answer for a part of a day, if one has been recorded
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+
+    /// Compare a computed answer against the known answer
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("[day01]\npart1 = 3\npart2 = \"6\"\n\n[day12]\npart1 = 2\n")
+            .expect("valid answers");
+        assert_eq!(answers.get(1, 1), Some("3"));
+        assert_eq!(answers.get(1, 2), Some("6"));
+        assert_eq!(answers.get(12, 1), Some("2"));
+        assert_eq!(answers.get(12, 2), None);
+    }
+
+    #[test]
+    fn test_parse_bad_day() {
+        assert!(Answers::parse("[first]\npart1 = 3\n").is_err())
+    }
+
+    #[test]
+    fn test_parse_bad_part() {
+        assert!(Answers::parse("[day01]\none = 3\n").is_err())
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[day01]\npart1 = 3\n").expect("valid answers");
+        assert_eq!(answers.check(1, 1, "3"), Verdict::Correct);
+        assert_eq!(
+            answers.check(1, 1, "4"),
+            Verdict::Wrong {
+                expected: "3".to_string()
+            }
+        );
+        assert_eq!(answers.check(1, 2, "6"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_committed_answers() {
+        let answers = Answers::load(&Answers::default_path()).expect("valid answers file");
+        assert!(answers.get(1, 1).is_some());
+    }
+}
@@ -0,0 +1,381 @@
+use crate::grid::Grid;
+use std::fmt;
+use std::str::FromStr;
+
+/// Which cells around a cell count as its neighbors
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Neighborhood {
+    /// The four orthogonally adjacent cells
+    VonNeumann,
+    /// The eight orthogonally or diagonally adjacent cells
+    Moore,
+    /// Every other cell in the square of this radius around the cell
+    MooreRadius(usize),
+    /// The six cells around a hexagonal cell, with odd rows shifted half a cell to the right.  With
+    /// a [`Boundary::Toroidal`] boundary the grid must have an even height, or wrapping from the
+    /// last row to the first would join two rows of the same parity.
+    Hex,
+}
+
+impl Neighborhood {
+    /// The `(row, column)` offsets of the neighbors of a cell in an even row and in an odd row,
+    /// which only differ for hex cells
+    fn offsets(&self) -> [Vec<(isize, isize)>; 2] {
+        let square = |radius: isize| -> Vec<(isize, isize)> {
+            (-radius..=radius)
+                .flat_map(|d_row| (-radius..=radius).map(move |d_column| (d_row, d_column)))
+                .filter(|offset| *offset != (0, 0))
+                .collect()
+        };
+        match self {
+            Neighborhood::VonNeumann => {
+                let offsets = vec![(-1, 0), (0, -1), (0, 1), (1, 0)];
+                [offsets.clone(), offsets]
+            }
+            Neighborhood::Moore => [square(1), square(1)],
+            Neighborhood::MooreRadius(radius) => {
+                let offsets = square(*radius as isize);
+                [offsets.clone(), offsets]
+            }
+            Neighborhood::Hex => [
+                vec![(-1, -1), (-1, 0), (0, -1), (0, 1), (1, -1), (1, 0)],
+                vec![(-1, 0), (-1, 1), (0, -1), (0, 1), (1, 0), (1, 1)],
+            ],
+        }
+    }
+}
+
+/// What lies past the edges of the grid
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Boundary {
+    /// Nothing, so edge cells have fewer neighbors
+    Bounded,
+    /// The opposite edge, so every cell has the same number of neighbors.  The grid should be
+    /// larger than the neighborhood, otherwise a cell can count the same neighbor twice.
+    Toroidal,
+}
+
+/// The neighbor counts at which a dead cell comes alive and a live cell stays alive
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Rule {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Rule {
+    pub fn new(
+        birth: impl IntoIterator<Item = usize>,
+        survival: impl IntoIterator<Item = usize>,
+    ) -> Rule {
+        let sorted = |counts: Vec<usize>| {
+            let mut counts = counts;
+            counts.sort_unstable();
+            counts.dedup();
+            counts
+        };
+        Rule {
+            birth: sorted(birth.into_iter().collect()),
+            survival: sorted(survival.into_iter().collect()),
+        }
+    }
+
+    /// Whether a cell is alive in the next generation, given whether it is alive now and how many
+    /// of its neighbors are
+    pub fn next_state(&self, alive: bool, count: usize) -> bool {
+        let counts = if alive { &self.survival } else { &self.birth };
+        counts.binary_search(&count).is_ok()
+    }
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    /// Parse the `B3/S23` notation, with counts above 9 separated by commas such as `B3/S4,10,11`
+    fn from_str(raw: &str) -> Result<Rule, String> {
+        let counts = |part: &str, prefix: &str| -> Result<Vec<usize>, String> {
+            let digits = part.strip_prefix(prefix).ok_or_else(|| {
+                format!("expected `{}` followed by counts, got `{}`", prefix, part)
+            })?;
+            let bad_count = || format!("expected counts after `{}`, got `{}`", prefix, digits);
+            if digits.contains(",") {
+                digits
+                    .split(",")
+                    .map(|count| count.parse::<usize>().map_err(|_| bad_count()))
+                    .collect()
+            } else {
+                digits
+                    .chars()
+                    .map(|char| {
+                        char.to_digit(10)
+                            .map(|count| count as usize)
+                            .ok_or_else(bad_count)
+                    })
+                    .collect()
+            }
+        };
+
+        let (birth, survival) = raw
+            .split_once("/")
+            .ok_or_else(|| format!("expected a rule such as `B3/S23`, got `{}`", raw))?;
+        Ok(Rule::new(counts(birth, "B")?, counts(survival, "S")?))
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = |counts: &[usize]| {
+            let separator = if counts.iter().any(|count| *count > 9) {
+                ","
+            } else {
+                ""
+            };
+            counts
+                .iter()
+                .map(|count| count.to_string())
+                .collect::<Vec<String>>()
+                .join(separator)
+        };
+        write!(f, "B{}/S{}", counts(&self.birth), counts(&self.survival))
+    }
+}
+
+/// A cellular automaton over a grid of live (`true`) and dead (`false`) cells, where every cell
+/// changes at once each generation according to how many of its neighbors are alive
+#[derive(Clone, Debug)]
+pub struct Automaton {
+    neighborhood: Neighborhood,
+    boundary: Boundary,
+    rule: Rule,
+    offsets: [Vec<(isize, isize)>; 2],
+}
+
+impl Automaton {
+    pub fn new(neighborhood: Neighborhood, boundary: Boundary, rule: Rule) -> Automaton {
+        Automaton {
+            neighborhood,
+            boundary,
+            rule,
+            offsets: neighborhood.offsets(),
+        }
+    }
+
+    pub fn neighborhood(&self) -> Neighborhood {
+        self.neighborhood
+    }
+
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    /// The positions of the neighbors of `(row, column)` in `grid`.  Panics if the neighborhood is
+    /// hex and the boundary toroidal but the grid has an odd height, as the wrapped neighbors
+    /// wouldn't be neighbors of each other.
+    pub fn neighbors<T>(
+        &self,
+        grid: &Grid<T>,
+        (row, column): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (grid.width(), grid.height());
+        assert!(
+            !(self.neighborhood == Neighborhood::Hex
+                && self.boundary == Boundary::Toroidal
+                && height % 2 == 1),
+            "a toroidal hex grid must have an even height, not {}",
+            height
+        );
+        let boundary = self.boundary;
+        self.offsets[row % 2]
+            .iter()
+            .filter_map(move |(d_row, d_column)| match boundary {
+                Boundary::Bounded => {
+                    let row = row.checked_add_signed(*d_row)?;
+                    let column = column.checked_add_signed(*d_column)?;
+                    (row < height && column < width).then_some((row, column))
+                }
+                Boundary::Toroidal => Some((
+                    (row as isize + d_row).rem_euclid(height as isize) as usize,
+                    (column as isize + d_column).rem_euclid(width as isize) as usize,
+                )),
+            })
+    }
+
+    /// Count the live neighbors of a position
+    pub fn count_neighbors(&self, grid: &Grid<bool>, pos: (usize, usize)) -> usize {
+        self.neighbors(grid, pos).filter(|pos| grid[*pos]).count()
+    }
+
+    /// Every position whose cell changes in the next generation, row by row
+    pub fn changes(&self, grid: &Grid<bool>) -> Vec<(usize, usize)> {
+        grid.iter()
+            .filter(|(pos, alive)| {
+                self.rule
+                    .next_state(**alive, self.count_neighbors(grid, *pos))
+                    != **alive
+            })
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// Advance the grid one generation, returning the number of cells that changed
+    pub fn step(&self, grid: &mut Grid<bool>) -> usize {
+        let changes = self.changes(grid);
+        for pos in changes.iter() {
+            grid[*pos] = !grid[*pos];
+        }
+        changes.len()
+    }
+
+    /// Advance the grid until a generation changes nothing or `max_steps` generations have run,
+    /// returning the number of cells that changed in each generation that changed any
+    pub fn run_until_stable(&self, grid: &mut Grid<bool>, max_steps: usize) -> Vec<usize> {
+        let mut changed: Vec<usize> = Vec::new();
+        for _ in 0..max_steps {
+            let count = self.step(grid);
+            if count == 0 {
+                break;
+            }
+            changed.push(count);
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn life() -> Automaton {
+        Automaton::new(
+            Neighborhood::Moore,
+            Boundary::Toroidal,
+            "B3/S23".parse().unwrap(),
+        )
+    }
+
+    fn parse(input: &str) -> Grid<bool> {
+        Grid::parse(input, |char| Some(char == '#'), "'#' or '.'").unwrap()
+    }
+
+    #[test]
+    fn test_parse_rule() {
+        assert_eq!("B3/S23".parse(), Ok(Rule::new([3], [2, 3])));
+        assert_eq!("B/S4,5,12".parse(), Ok(Rule::new([], [4, 5, 12])));
+        assert!("B3S23".parse::<Rule>().is_err());
+        assert!("S23/B3".parse::<Rule>().is_err());
+        assert!("B3/S2x".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn test_display_rule() {
+        for rule in [Rule::new([3], [2, 3]), Rule::new([], [4, 10, 24])] {
+            assert_eq!(rule.to_string().parse(), Ok(rule));
+        }
+    }
+
+    #[test]
+    fn test_next_state() {
+        let rule = Rule::new([3], [2, 3]);
+        assert!(rule.next_state(false, 3));
+        assert!(!rule.next_state(false, 2));
+        assert!(rule.next_state(true, 2));
+        assert!(!rule.next_state(true, 4));
+    }
+
+    #[test]
+    fn test_neighborhood_sizes() {
+        let grid = Grid::new(7, 8, false);
+        for (neighborhood, size) in [
+            (Neighborhood::VonNeumann, 4),
+            (Neighborhood::Moore, 8),
+            (Neighborhood::MooreRadius(2), 24),
+            (Neighborhood::Hex, 6),
+        ] {
+            for boundary in [Boundary::Bounded, Boundary::Toroidal] {
+                let automaton = Automaton::new(neighborhood, boundary, Rule::new([], []));
+                assert_eq!(automaton.neighbors(&grid, (3, 3)).count(), size);
+                assert_eq!(automaton.neighbors(&grid, (4, 3)).count(), size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_neighbors_at_edges() {
+        let grid = Grid::new(4, 3, false);
+        let bounded = Automaton::new(Neighborhood::Moore, Boundary::Bounded, Rule::new([], []));
+        assert_eq!(bounded.neighbors(&grid, (0, 0)).count(), 3);
+
+        let toroidal = Automaton::new(
+            Neighborhood::VonNeumann,
+            Boundary::Toroidal,
+            Rule::new([], []),
+        );
+        assert_eq!(
+            toroidal.neighbors(&grid, (0, 0)).collect::<Vec<_>>(),
+            vec![(2, 0), (0, 3), (0, 1), (1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_hex_neighbors() {
+        let grid = Grid::new(3, 3, false);
+        let hex = Automaton::new(Neighborhood::Hex, Boundary::Bounded, Rule::new([], []));
+        assert_eq!(
+            hex.neighbors(&grid, (1, 0)).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (1, 1), (2, 0), (2, 1)]
+        );
+        assert_eq!(
+            hex.neighbors(&grid, (2, 2)).collect::<Vec<_>>(),
+            vec![(1, 1), (1, 2), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn test_toroidal_hex_neighbors_are_symmetric() {
+        let grid = Grid::new(5, 4, false);
+        let hex = Automaton::new(Neighborhood::Hex, Boundary::Toroidal, Rule::new([], []));
+        for pos in grid.positions() {
+            for neighbor in hex.neighbors(&grid, pos) {
+                assert!(hex.neighbors(&grid, neighbor).any(|back| back == pos));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "a toroidal hex grid must have an even height, not 3")]
+    fn test_toroidal_hex_odd_height() {
+        let hex = Automaton::new(Neighborhood::Hex, Boundary::Toroidal, Rule::new([], []));
+        hex.neighbors(&Grid::new(3, 3, false), (0, 0)).count();
+    }
+
+    #[test]
+    fn test_blinker() {
+        let mut grid = parse(".....\n.....\n.###.\n.....\n.....\n");
+        let vertical = parse(".....\n..#..\n..#..\n..#..\n.....\n");
+        assert_eq!(life().step(&mut grid), 4);
+        assert_eq!(grid, vertical);
+        life().step(&mut grid);
+        assert_eq!(grid, parse(".....\n.....\n.###.\n.....\n.....\n"));
+    }
+
+    #[test]
+    fn test_glider_wraps() {
+        let start = parse(".#....\n..#...\n###...\n......\n......\n......\n");
+        let mut grid = start.clone();
+        // a glider moves one cell diagonally every four generations
+        assert_eq!(life().run_until_stable(&mut grid, 24).len(), 24);
+        assert_eq!(grid, start);
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        // a block is a still life, so the pond loses only its stray cell
+        let mut grid = parse("##...\n##...\n.....\n....#\n");
+        let bounded = Automaton::new(Neighborhood::Moore, Boundary::Bounded, life().rule);
+        assert_eq!(bounded.run_until_stable(&mut grid, 10), vec![1]);
+        assert_eq!(grid, parse("##...\n##...\n.....\n.....\n"));
+    }
+}
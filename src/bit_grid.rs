@@ -0,0 +1,357 @@
+use crate::error::ParseError;
+use crate::grid::Grid;
+
+/// A rectangular 2D grid of bits, packed 64 to a word.  Each row starts on a new word, with
+/// column `c` in bit `c % 64` of the row's word `c / 64`, and the bits past the end of a row are
+/// always clear.  Positions are `(row, column)` pairs like in [`Grid`].
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+    /// A row of clear words, standing in for the rows past the top and bottom edges
+    zero_row: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Create a grid with every bit clear
+    pub fn new(width: usize, height: usize) -> BitGrid {
+        let words_per_row = width.div_ceil(64);
+        BitGrid {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+            zero_row: vec![0; words_per_row],
+        }
+    }
+
+    /// Parse a char map, setting the bits of the chars that `parse_cell` turns into `true` and
+    /// describing the chars it accepts with `expected`.  Rows are packed as they are read, so the
+    /// map is never held a byte per cell.  Empty lines are skipped, and every other line must be
+    /// as long as the first.
+    pub fn parse(
+        input: &str,
+        parse_cell: impl Fn(char) -> Option<bool>,
+        expected: &str,
+    ) -> Result<BitGrid, ParseError> {
+        let mut grid: Option<BitGrid> = None;
+        for (line_i, line) in input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+        {
+            let grid = grid.get_or_insert_with(|| BitGrid::new(line.chars().count(), 0));
+            let row = grid.height;
+            grid.height += 1;
+            grid.words.resize(grid.words.len() + grid.words_per_row, 0);
+
+            let mut len: usize = 0;
+            for (i, char) in line.chars().enumerate() {
+                let set = parse_cell(char)
+                    .ok_or_else(|| ParseError::new(line, i + 1, expected).at_line(line_i + 1))?;
+                if i < grid.width && set {
+                    grid.set(row, i, true);
+                }
+                len += 1;
+            }
+            if len != grid.width {
+                return Err(ParseError::new(
+                    line,
+                    grid.width.min(len) + 1,
+                    format!("a row of {} cells", grid.width),
+                )
+                .at_line(line_i + 1));
+            }
+        }
+        Ok(grid.unwrap_or_else(|| BitGrid::new(0, 0)))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The bit at `(row, column)`, or `None` if that is outside the grid
+    pub fn get(&self, row: usize, column: usize) -> Option<bool> {
+        (row < self.height && column < self.width)
+            .then(|| self.row_words(row)[column / 64] & (1 << (column % 64)) != 0)
+    }
+
+    /// Set or clear the bit at `(row, column)`.  Panics if that is outside the grid.
+    pub fn set(&mut self, row: usize, column: usize, value: bool) {
+        assert!(
+            row < self.height && column < self.width,
+            "({}, {}) is outside the grid",
+            row,
+            column
+        );
+        let word = &mut self.row_words_mut(row)[column / 64];
+        if value {
+            *word |= 1 << (column % 64);
+        } else {
+            *word &= !(1 << (column % 64));
+        }
+    }
+
+    /// The words of a row, left to right.  Panics if the row is outside the grid.
+    pub fn row_words(&self, row: usize) -> &[u64] {
+        assert!(row < self.height, "row {} is outside the grid", row);
+        &self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    /// The words of a row for updating.  Panics if the row is outside the grid.
+    pub fn row_words_mut(&mut self, row: usize) -> &mut [u64] {
+        assert!(row < self.height, "row {} is outside the grid", row);
+        &mut self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    /// The number of set bits
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The position of every set bit, row by row
+    pub fn ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, word)| {
+            let (row, first_column) = (i / self.words_per_row, i % self.words_per_row * 64);
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    (row, first_column + bit)
+                })
+            })
+        })
+    }
+
+    /// The mask of the bits of a row's last word that are inside the grid
+    fn last_word_mask(&self) -> u64 {
+        match self.width % 64 {
+            0 => u64::MAX,
+            bits => (1 << bits) - 1,
+        }
+    }
+
+    /// For every cell of `row`, whether fewer than `threshold` of its eight neighbors are set,
+    /// written to `out` as the words of a row.  The neighbors are counted 64 cells at a time by
+    /// shifting the rows above, below and at `row` by a column each way and adding the eight
+    /// resulting words as 4-bit counters spread across four words.
+    pub fn fewer_neighbors_in_row(&self, row: usize, threshold: usize, out: &mut [u64]) {
+        assert!(row < self.height, "row {} is outside the grid", row);
+        assert_eq!(
+            out.len(),
+            self.words_per_row,
+            "output must be a row of words"
+        );
+        let above = if row > 0 {
+            self.row_words(row - 1)
+        } else {
+            &self.zero_row
+        };
+        let below = if row + 1 < self.height {
+            self.row_words(row + 1)
+        } else {
+            &self.zero_row
+        };
+        let current = self.row_words(row);
+
+        let n = self.words_per_row;
+        for (k, out_word) in out.iter_mut().enumerate() {
+            // the neighbor to the west of column `c` is in column `c - 1`, so shifts left
+            let west = |words: &[u64]| (words[k] << 1) | if k > 0 { words[k - 1] >> 63 } else { 0 };
+            let east =
+                |words: &[u64]| (words[k] >> 1) | if k + 1 < n { words[k + 1] << 63 } else { 0 };
+            let neighbors = [
+                west(above),
+                above[k],
+                east(above),
+                west(current),
+                east(current),
+                west(below),
+                below[k],
+                east(below),
+            ];
+
+            let mut counts = [0u64; 4];
+            for neighbor in neighbors {
+                let mut carry = neighbor;
+                for count in counts.iter_mut() {
+                    let next_carry = *count & carry;
+                    *count ^= carry;
+                    carry = next_carry;
+                }
+            }
+
+            // compare the counters against the threshold a bit at a time, from the top bit
+            let mut less = 0;
+            let mut equal = u64::MAX;
+            for (bit, count) in counts.iter().enumerate().rev() {
+                if threshold >> bit & 1 == 1 {
+                    less |= equal & !count;
+                    equal &= count;
+                } else {
+                    equal &= !count;
+                }
+            }
+            if threshold >= 16 {
+                less = u64::MAX;
+            }
+            if k + 1 == n {
+                less &= self.last_word_mask();
+            }
+            *out_word = less;
+        }
+    }
+
+    /// Whether fewer than `threshold` of the eight neighbors of each cell are set
+    pub fn fewer_neighbors(&self, threshold: usize) -> BitGrid {
+        let mut result = BitGrid::new(self.width, self.height);
+        for row in 0..self.height {
+            self.fewer_neighbors_in_row(row, threshold, result.row_words_mut(row));
+        }
+        result
+    }
+
+    /// Keep only the bits that are also set in `other`.  Panics if the grids are different shapes.
+    pub fn retain_all(&mut self, other: &BitGrid) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "grids must be the same shape"
+        );
+        for (word, other) in self.words.iter_mut().zip(other.words.iter()) {
+            *word &= other;
+        }
+    }
+
+    /// Unpack the grid into a cell per bit
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid::from_rows(
+            (0..self.height)
+                .map(|row| {
+                    (0..self.width)
+                        .map(|column| self.get(row, column) == Some(true))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> BitGrid {
+        let mut bits = BitGrid::new(grid.width(), grid.height());
+        for ((row, column), set) in grid.iter() {
+            if *set {
+                bits.set(row, column, true);
+            }
+        }
+        bits
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn parse(input: &str) -> BitGrid {
+        BitGrid::parse(input, |char| Some(char == '#'), "'#' or '.'").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = parse("#..\n\n.##\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row_words(0), &[0b001]);
+        assert_eq!(grid.row_words(1), &[0b110]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |input| {
+            BitGrid::parse(
+                input,
+                |char| (char != 'x').then_some(char == '#'),
+                "'#' or '.'",
+            )
+        };
+        assert_eq!(
+            parse("#.\n.x"),
+            Err(ParseError::new(".x", 2, "'#' or '.'").at_line(2))
+        );
+        assert_eq!(
+            parse("##\n#"),
+            Err(ParseError::new("#", 2, "a row of 2 cells").at_line(2))
+        );
+        assert_eq!(
+            parse("##\n###"),
+            Err(ParseError::new("###", 3, "a row of 2 cells").at_line(2))
+        );
+    }
+
+    #[test]
+    fn test_get_and_set() {
+        let mut grid = BitGrid::new(130, 2);
+        grid.set(1, 129, true);
+        grid.set(0, 64, true);
+        assert_eq!(grid.get(1, 129), Some(true));
+        assert_eq!(grid.get(1, 128), Some(false));
+        assert_eq!(grid.get(1, 130), None);
+        assert_eq!(grid.row_words(1), &[0, 0, 0b10]);
+        assert_eq!(grid.ones().collect::<Vec<_>>(), vec![(0, 64), (1, 129)]);
+        grid.set(0, 64, false);
+        assert_eq!(grid.count_ones(), 1);
+    }
+
+    #[test]
+    fn test_fewer_neighbors() {
+        let grid = parse("###\n###\n###\n");
+        assert_eq!(
+            grid.fewer_neighbors(4).to_grid(),
+            parse("#.#\n...\n#.#\n").to_grid()
+        );
+        assert_eq!(grid.fewer_neighbors(9).count_ones(), 9);
+        assert_eq!(grid.fewer_neighbors(0).count_ones(), 0);
+    }
+
+    #[test]
+    fn test_grid_round_trip() {
+        let grid = Grid::from_rows(vec![vec![true, false, true], vec![false, false, true]]);
+        assert_eq!(BitGrid::from(&grid).to_grid(), grid);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_fewer_neighbors_matches_grid(
+            rows in (1..140usize, 1..6usize).prop_flat_map(|(width, height)| {
+                prop::collection::vec(prop::collection::vec(any::<bool>(), width), height)
+            }),
+            threshold in 0..10usize,
+        ) {
+            let grid = Grid::from_rows(rows);
+            let expected = Grid::from_rows(
+                (0..grid.height())
+                    .map(|row| {
+                        (0..grid.width())
+                            .map(|column| {
+                                grid.neighbors8(row, column).filter(|pos| grid[*pos]).count()
+                                    < threshold
+                            })
+                            .collect()
+                    })
+                    .collect(),
+            );
+            prop_assert_eq!(BitGrid::from(&grid).fewer_neighbors(threshold).to_grid(), expected);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::Solution;
+use crate::error::{ParseError, parse_non_empty_lines, parse_number};
+
+pub mod prob1;
+pub mod prob2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Cmd>;
+    type Output = u16;
+
+    const EXAMPLE: &'static str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    fn parse(input: &str) -> Result<Vec<Cmd>, ParseError> {
+        parse_non_empty_lines(input, Cmd::from_line)
+    }
+
+    fn part1(cmds: Vec<Cmd>) -> u16 {
+        let mut dial = prob1::Dial::default();
+        cmds.into_iter().for_each(|cmd| dial.spin(cmd));
+        dial.times_on_zero
+    }
+
+    fn part2(cmds: Vec<Cmd>) -> Option<u16> {
+        let mut dial = prob2::Dial::default();
+        cmds.into_iter().for_each(|cmd| dial.spin(cmd));
+        Some(dial.times_past_zero)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Cmd {
+    Left(u16),
+    Right(u16),
+}
+
+impl Cmd {
+    pub fn from_line(line: &str) -> Result<Cmd, ParseError> {
+        let mut chars = line.chars();
+        let dir = chars.next();
+        let amt = parse_number::<u16>(line, chars.as_str());
+
+        match dir {
+            Some('L') => Ok(Cmd::Left(amt?)),
+            Some('R') => Ok(Cmd::Right(amt?)),
+            _ => Err(ParseError::new(line, 1, "a direction ('L' or 'R')")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_line_left() {
+        assert_eq!(Cmd::from_line("L50"), Ok(Cmd::Left(50)))
+    }
+
+    #[test]
+    fn test_from_line_right() {
+        assert_eq!(Cmd::from_line("R9"), Ok(Cmd::Right(9)))
+    }
+
+    #[test]
+    fn test_from_line_unknown_direction() {
+        assert_eq!(
+            Cmd::from_line("U9"),
+            Err(ParseError::new("U9", 1, "a direction ('L' or 'R')"))
+        )
+    }
+
+    #[test]
+    fn test_from_line_bad_amount() {
+        assert_eq!(
+            Cmd::from_line("L5x"),
+            Err(ParseError::new("L5x", 2, "a number"))
+        )
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day01::part1(Day01::parse(Day01::EXAMPLE).unwrap()), 3);
+        assert_eq!(
+            Day01::part2(Day01::parse(Day01::EXAMPLE_PART2).unwrap()),
+            Some(6)
+        );
+    }
+}
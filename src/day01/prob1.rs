@@ -1,45 +1,9 @@
-fn main() {
-    let cmds = std::fs::read_to_string("src/bin/day01.txt")
-        .map(|file| {
-            file.lines()
-                .filter(|line| !line.is_empty())
-                .map(Cmd::from_line)
-                .collect::<Vec<Cmd>>()
-        })
-        .expect("Unable to open file");
-    let mut dial = Dial::default();
-    cmds.into_iter().for_each(|cmd| dial.spin(cmd));
-    println!("{:?}", dial);
-}
+use super::Cmd;
 
 #[derive(Debug, PartialEq)]
-enum Cmd {
-    Left(u16),
-    Right(u16),
-}
-
-impl Cmd {
-    pub fn from_line(line: &str) -> Cmd {
-        let chars: Vec<char> = line.chars().collect();
-        let dir = chars[0];
-        let amt = chars[1..]
-            .iter()
-            .collect::<String>()
-            .parse::<u16>()
-            .expect("unable to parse");
-
-        match dir {
-            'L' => Cmd::Left(amt),
-            'R' => Cmd::Right(amt),
-            x => panic!("Unknown direction: {}", x),
-        }
-    }
-}
-
-#[derive(Debug, PartialEq)]
-struct Dial {
-    pos: u16,
-    times_on_zero: u16,
+pub struct Dial {
+    pub pos: u16,
+    pub times_on_zero: u16,
 }
 
 impl Default for Dial {
@@ -72,16 +36,6 @@ impl Dial {
 mod test {
     use super::*;
 
-    #[test]
-    fn test_from_line_left() {
-        assert_eq!(Cmd::from_line("L50"), Cmd::Left(50))
-    }
-
-    #[test]
-    fn test_from_line_right() {
-        assert_eq!(Cmd::from_line("R9"), Cmd::Right(9))
-    }
-
     #[test]
     fn test_spin_right() {
         let mut dial = Dial {
@@ -0,0 +1,209 @@
+use crate::Solution;
+use crate::error::{ParseError, parse_non_empty_lines, parse_numbers};
+use crate::interval_set::IntervalSet;
+
+pub mod prob1;
+pub mod prob2;
+pub mod rule;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(u64, u64)>;
+    type Output = u128;
+
+    const EXAMPLE: &'static str =
+        "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,\
+38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
+";
+
+    fn parse(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+        parse_in_base(input, 10)
+    }
+
+    fn part1(ranges: Vec<(u64, u64)>) -> u128 {
+        prob1::find_invalid_ids_all(prepare_ranges(ranges, false))
+    }
+
+    fn part2(ranges: Vec<(u64, u64)>) -> Option<u128> {
+        Some(prob2::find_invalid_ids_all(prepare_ranges(ranges, false)))
+    }
+}
+
+pub fn parse_line(line: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    parse_line_in_base(line, 10)
+}
+
+/// Parse a line of comma-separated ranges whose IDs are written in `base`, such as `1a-2f` in
+/// base 16
+pub fn parse_line_in_base(line: &str, base: u32) -> Result<Vec<(u64, u64)>, ParseError> {
+    let expected = if base == 10 {
+        "a number".to_string()
+    } else {
+        format!("a base {} number", base)
+    };
+    let parse_id = |token: &str| {
+        u64::from_str_radix(token, base).map_err(|_| ParseError::at_token(line, token, &expected))
+    };
+
+    line.split(",")
+        .map(|chunk| {
+            // check the shape of the range before splitting it, so that a missing or extra
+            // separator is reported the same way as for the other days
+            parse_numbers::<String, 2>(line, chunk, "-")?;
+            let (start, end) = chunk.split_once("-").expect("range has a separator");
+            Ok((parse_id(start)?, parse_id(end)?))
+        })
+        .collect()
+}
+
+/// Parse the ranges of an input whose IDs are written in `base`
+pub fn parse_in_base(input: &str, base: u32) -> Result<Vec<(u64, u64)>, ParseError> {
+    parse_non_empty_lines(input, |line| parse_line_in_base(line, base))?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ParseError::new("", 1, "a line of comma-separated ranges"))
+}
+
+/// Find every pair of ranges that share at least one ID, each with the earlier range (by start)
+/// first.  Sweeping the ranges by start while keeping those that haven't ended yet active pairs
+/// each range with every active range, as those are exactly the earlier ranges that reach it.
+pub fn find_overlaps(ranges: &[(u64, u64)]) -> Vec<((u64, u64), (u64, u64))> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort();
+
+    let mut overlaps: Vec<((u64, u64), (u64, u64))> = Vec::new();
+    let mut active: Vec<(u64, u64)> = Vec::new();
+    for range in sorted.into_iter() {
+        active.retain(|prev| range.0 <= prev.1);
+        overlaps.extend(active.iter().map(|prev| (*prev, range)));
+        active.push(range);
+    }
+    overlaps
+}
+
+/// Merge overlapping and adjacent ranges so that no ID is searched twice
+pub fn merge_ranges(ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+    ranges
+        .into_iter()
+        .collect::<IntervalSet<u64>>()
+        .ranges()
+        .to_vec()
+}
+
+/// Warn about any overlapping ranges, whose shared IDs are counted once per range unless `merge`
+/// is set, in which case the ranges are merged before searching
+pub fn prepare_ranges(ranges: Vec<(u64, u64)>, merge: bool) -> Vec<(u64, u64)> {
+    let overlaps = find_overlaps(&ranges);
+    for (first, second) in overlaps.iter() {
+        log::warn!(
+            "Ranges {}-{} and {}-{} overlap",
+            first.0,
+            first.1,
+            second.0,
+            second.1
+        );
+    }
+
+    if merge {
+        merge_ranges(ranges)
+    } else {
+        if !overlaps.is_empty() {
+            log::warn!(
+                "Found {} overlapping pairs of ranges, so the IDs they share are counted more \
+                 than once",
+                overlaps.len()
+            );
+        }
+        ranges
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("11-22,95-115,2121212118-2121212124"),
+            Ok(vec![(11, 22), (95, 115), (2121212118, 2121212124)])
+        )
+    }
+
+    #[test]
+    fn test_parse_line_missing_end() {
+        assert_eq!(
+            parse_line("11-22,95,998-1012"),
+            Err(ParseError::new(
+                "11-22,95,998-1012",
+                9,
+                "2 numbers separated by '-'"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_line_in_base() {
+        assert_eq!(
+            parse_line_in_base("1a-2F,ff-100", 16),
+            Ok(vec![(0x1a, 0x2f), (0xff, 0x100)])
+        );
+        assert_eq!(
+            parse_line_in_base("1a-2g", 16),
+            Err(ParseError::new("1a-2g", 4, "a base 16 number"))
+        );
+        assert_eq!(
+            parse_line_in_base("1a-2f-3", 16),
+            Err(ParseError::new("1a-2f-3", 6, "2 numbers separated by '-'"))
+        );
+    }
+
+    #[test]
+    fn test_find_overlaps() {
+        assert_eq!(
+            find_overlaps(&[(30, 40), (1, 10), (5, 8), (9, 12), (13, 20)]),
+            vec![((1, 10), (5, 8)), ((1, 10), (9, 12))]
+        );
+        assert_eq!(find_overlaps(&[(1, 10), (11, 20)]), vec![]);
+        assert_eq!(
+            find_overlaps(&[(3, 8), (1, 10), (2, 9)]),
+            vec![((1, 10), (2, 9)), ((1, 10), (3, 8)), ((2, 9), (3, 8))]
+        );
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        assert_eq!(
+            merge_ranges(vec![(30, 40), (1, 10), (5, 8), (9, 12), (13, 20)]),
+            vec![(1, 20), (30, 40)]
+        )
+    }
+
+    #[test]
+    fn test_prepare_ranges() {
+        let ranges = vec![(11, 22), (20, 33)];
+        assert_eq!(prepare_ranges(ranges.clone(), false), ranges);
+        assert_eq!(prepare_ranges(ranges.clone(), true), vec![(11, 33)]);
+        assert_eq!(
+            prob1::find_invalid_ids_all(prepare_ranges(ranges.clone(), false)),
+            88
+        );
+        assert_eq!(
+            prob1::find_invalid_ids_all(prepare_ranges(ranges, true)),
+            66
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(
+            Day02::part1(Day02::parse(Day02::EXAMPLE).unwrap()),
+            1227775554
+        );
+        assert_eq!(
+            Day02::part2(Day02::parse(Day02::EXAMPLE_PART2).unwrap()),
+            Some(4174379265)
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use super::rule::{Rule, invalid_ids, sum_invalid_ids};
+
+/// Sum the invalid IDs of every range, without collecting them, in a sum wide enough for the IDs
+/// of even the widest ranges
+pub fn find_invalid_ids_all(ranges: Vec<(u64, u64)>) -> u128 {
+    ranges
+        .into_iter()
+        .map(|range| sum_invalid_ids(range, Rule::AtLeast(2), 10))
+        .sum()
+}
+
+/// Find the IDs made of some sequence of digits repeated at least twice.  IDs such as `111111`
+/// are made of several different repeated sequences, so are only counted once.
+pub fn find_invalid_ids(range: (u64, u64)) -> Vec<u64> {
+    invalid_ids(range, Rule::AtLeast(2), 10)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_invalid_ids() {
+        assert_eq!(find_invalid_ids((11, 22)), vec![11, 22])
+    }
+
+    #[test]
+    fn test_find_invalid_ids2() {
+        assert_eq!(find_invalid_ids((95, 115)), vec![99, 111])
+    }
+
+    #[test]
+    fn test_find_invalid_ids3() {
+        assert_eq!(find_invalid_ids((565653, 565659)), vec![565656])
+    }
+
+    #[test]
+    fn test_find_invalid_ids_dedup() {
+        assert_eq!(find_invalid_ids((111110, 111112)), vec![111111])
+    }
+
+    #[test]
+    fn test_find_invalid_ids_all() {
+        assert_eq!(
+            find_invalid_ids_all(vec![
+                (11, 22),
+                (95, 115),
+                (998, 1012),
+                (1188511880, 1188511890),
+                (222220, 222224),
+                (1698522, 1698528),
+                (446443, 446449),
+                (38593856, 38593862),
+                (565653, 565659),
+                (824824821, 824824827),
+                (2121212118, 2121212124)
+            ]),
+            4174379265
+        )
+    }
+}
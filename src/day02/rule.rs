@@ -0,0 +1,362 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A rule for which IDs are invalid, judged by the digits of the ID written in some base
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rule {
+    /// A sequence of digits repeated exactly this many times, such as `123123` for 2
+    Exactly(u32),
+    /// A sequence of digits repeated at least this many times
+    AtLeast(u32),
+    /// The same digits forwards and backwards, such as `12321`
+    Palindrome,
+    /// A sequence of exactly this many digits repeated at least twice, such as `1212` for 2
+    Unit(u32),
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    /// Parse `exactly:K`, `at-least:K`, `palindrome` or `unit:N`
+    fn from_str(raw: &str) -> Result<Rule, String> {
+        let (name, arg) = match raw.split_once(":") {
+            Some((name, arg)) => (name, Some(arg)),
+            None => (raw, None),
+        };
+        let count = || {
+            arg.and_then(|arg| arg.parse::<u32>().ok())
+                .filter(|count| *count > 0)
+                .ok_or_else(|| format!("expected a positive count after `{}:`", name))
+        };
+
+        match name {
+            "exactly" => Ok(Rule::Exactly(count()?)),
+            "at-least" => Ok(Rule::AtLeast(count()?)),
+            "unit" => Ok(Rule::Unit(count()?)),
+            "palindrome" if arg.is_none() => Ok(Rule::Palindrome),
+            _ => Err(format!(
+                "expected `exactly:K`, `at-least:K`, `palindrome` or `unit:N`, got `{}`",
+                raw
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::Exactly(count) => write!(f, "exactly:{}", count),
+            Rule::AtLeast(count) => write!(f, "at-least:{}", count),
+            Rule::Palindrome => write!(f, "palindrome"),
+            Rule::Unit(len) => write!(f, "unit:{}", len),
+        }
+    }
+}
+
+/// The number of digits of the largest `u64` in `base`
+fn max_digits(base: u32) -> u32 {
+    u64::MAX.ilog(base as u64) + 1
+}
+
+/// Find every ID in `range` that `rule` makes invalid when the ID is written in `base`, in
+/// ascending order.  The IDs are generated directly from their digit patterns rather than by
+/// scanning the range, so the cost depends on the number of matches rather than the width of the
+/// range.
+pub fn invalid_ids(range: (u64, u64), rule: Rule, base: u32) -> Vec<u64> {
+    assert!((2..=36).contains(&base), "base must be from 2 to 36");
+    let max_digits = max_digits(base);
+    match rule {
+        Rule::Exactly(repeats) => repeated_ids(range, repeats, base),
+        Rule::AtLeast(repeats) => {
+            // IDs such as `111111` are made of several different repeated sequences, so are
+            // generated more than once
+            let mut ids: Vec<u64> = (repeats..=max_digits.max(repeats))
+                .flat_map(|repeats| repeated_ids(range, repeats, base))
+                .collect();
+            ids.sort_unstable();
+            ids.dedup();
+            ids
+        }
+        Rule::Palindrome => palindromic_ids(range, base),
+        // more repeats make longer IDs, so these come out in order
+        Rule::Unit(unit_len) => (2..=max_digits / unit_len)
+            .flat_map(|repeats| repeated_unit_ids(range, unit_len, repeats, base))
+            .collect(),
+    }
+}
+
+/// The multiplier that repeats a pattern of `unit_len` digits exactly `repeats` times, such as
+/// `10101`, along with the smallest and largest patterns whose repeats fall in `range`
+fn repeat_bounds(range: (u64, u64), unit_len: u32, repeats: u32, base: u32) -> (u128, u128, u128) {
+    let (start, end) = (range.0 as u128, range.1 as u128);
+    let unit = (base as u128).pow(unit_len);
+    let multiplier: u128 = (0..repeats).map(|i| unit.pow(i)).sum();
+    // patterns have exactly `unit_len` digits, so no leading zeros
+    let min_pattern = (unit / base as u128).max(start.div_ceil(multiplier));
+    let max_pattern = (unit - 1).min(end / multiplier);
+    (multiplier, min_pattern, max_pattern)
+}
+
+/// Generate every ID in `range` that is a pattern of `unit_len` digits repeated exactly `repeats`
+/// times, each built directly as the pattern times a multiplier such as `10101`
+fn repeated_unit_ids(
+    range: (u64, u64),
+    unit_len: u32,
+    repeats: u32,
+    base: u32,
+) -> impl Iterator<Item = u64> {
+    let (multiplier, min_pattern, max_pattern) = repeat_bounds(range, unit_len, repeats, base);
+    (min_pattern..=max_pattern).map(move |pattern| (pattern * multiplier) as u64)
+}
+
+/// Sum the IDs that [`repeated_unit_ids`] generates without generating them.  The patterns are
+/// consecutive, so their sum is an arithmetic series, which the multiplier then repeats.
+fn repeated_unit_sum(range: (u64, u64), unit_len: u32, repeats: u32, base: u32) -> u128 {
+    let (multiplier, min_pattern, max_pattern) = repeat_bounds(range, unit_len, repeats, base);
+    if min_pattern > max_pattern {
+        return 0;
+    }
+    multiplier * ((min_pattern + max_pattern) * (max_pattern - min_pattern + 1) / 2)
+}
+
+/// Sum the IDs in `range` of exactly `len` digits that are some pattern repeated at least
+/// `repeats` times.  An ID made of a pattern of `d` digits is also made of a pattern of every
+/// multiple of `d` that divides `len`, so the IDs whose shortest pattern has `d` digits are found
+/// by taking those with a shorter shortest pattern dividing `d` away from all of the repeats of
+/// `d` digit patterns.
+fn at_least_sum(range: (u64, u64), len: u32, repeats: u32, base: u32) -> u128 {
+    let unit_lens: Vec<u32> = (1..=len)
+        .filter(|unit_len| len.is_multiple_of(*unit_len) && len / unit_len >= repeats)
+        .collect();
+    // the sums of the IDs whose shortest pattern has each of `unit_lens` digits
+    let mut shortest: Vec<u128> = Vec::with_capacity(unit_lens.len());
+    for (i, unit_len) in unit_lens.iter().enumerate() {
+        let longer = (0..i)
+            .filter(|j| unit_len % unit_lens[*j] == 0)
+            .map(|j| shortest[j])
+            .sum::<u128>();
+        shortest.push(repeated_unit_sum(range, *unit_len, len / unit_len, base) - longer);
+    }
+    shortest.iter().sum()
+}
+
+/// Sum every ID in `range` that `rule` makes invalid when the ID is written in `base`.  The
+/// repeated patterns are summed a block of patterns at a time in closed form, so even the whole
+/// `u64` domain is summed without visiting each ID, while palindromes are summed as they are
+/// generated rather than collected.
+pub fn sum_invalid_ids(range: (u64, u64), rule: Rule, base: u32) -> u128 {
+    assert!((2..=36).contains(&base), "base must be from 2 to 36");
+    let max_digits = max_digits(base);
+    match rule {
+        Rule::Exactly(repeats) => {
+            assert!(repeats > 0, "a pattern must be repeated at least once");
+            (1..=max_digits / repeats)
+                .map(|unit_len| repeated_unit_sum(range, unit_len, repeats, base))
+                .sum()
+        }
+        Rule::AtLeast(repeats) => (1..=max_digits)
+            .map(|len| at_least_sum(range, len, repeats, base))
+            .sum(),
+        Rule::Palindrome => palindromes(range, base).map(u128::from).sum(),
+        Rule::Unit(unit_len) => (2..=max_digits / unit_len)
+            .map(|repeats| repeated_unit_sum(range, unit_len, repeats, base))
+            .sum(),
+    }
+}
+
+/// Generate every ID in `range` whose digits are a pattern repeated exactly `repeats` times, in
+/// ascending order
+pub fn repeated_ids(range: (u64, u64), repeats: u32, base: u32) -> Vec<u64> {
+    assert!(repeats > 0, "a pattern must be repeated at least once");
+    (1..=max_digits(base) / repeats)
+        .flat_map(|unit_len| repeated_unit_ids(range, unit_len, repeats, base))
+        .collect()
+}
+
+/// Reverse the digits of `num` in `base`
+fn reverse_digits(mut num: u128, base: u128) -> u128 {
+    let mut reversed = 0;
+    while num > 0 {
+        reversed = reversed * base + num % base;
+        num /= base;
+    }
+    reversed
+}
+
+/// Generate every ID in `range` whose digits read the same forwards and backwards, in ascending
+/// order.  Each palindrome is built by mirroring its first half, starting from the first half of
+/// the start of the range.
+fn palindromes(range: (u64, u64), base: u32) -> impl Iterator<Item = u64> {
+    let (start, end) = (range.0 as u128, range.1 as u128);
+    let max_digits = max_digits(base);
+    let base = base as u128;
+    (1..=max_digits).flat_map(move |len| {
+        let half_len = len.div_ceil(2);
+        let shift = base.pow(len - half_len);
+        let (min_half, max_half) = (base.pow(half_len - 1), base.pow(half_len) - 1);
+        (min_half.max(start / shift)..=max_half)
+            .map(move |half| {
+                // the middle digit of an odd length palindrome isn't mirrored
+                let mirrored = if len % 2 == 1 { half / base } else { half };
+                half * shift + reverse_digits(mirrored, base)
+            })
+            .take_while(move |id| *id <= end)
+            .filter(move |id| *id >= start)
+            .map(|id| id as u64)
+    })
+}
+
+/// Collect every ID in `range` whose digits read the same forwards and backwards, in ascending
+/// order
+pub fn palindromic_ids(range: (u64, u64), base: u32) -> Vec<u64> {
+    palindromes(range, base).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_rule() {
+        assert_eq!("exactly:3".parse(), Ok(Rule::Exactly(3)));
+        assert_eq!("at-least:2".parse(), Ok(Rule::AtLeast(2)));
+        assert_eq!("palindrome".parse(), Ok(Rule::Palindrome));
+        assert_eq!("unit:4".parse(), Ok(Rule::Unit(4)));
+        assert!("exactly:0".parse::<Rule>().is_err());
+        assert!("unit".parse::<Rule>().is_err());
+        assert!("halves".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn test_display_rule() {
+        for rule in [
+            Rule::Exactly(3),
+            Rule::AtLeast(2),
+            Rule::Palindrome,
+            Rule::Unit(4),
+        ] {
+            assert_eq!(rule.to_string().parse(), Ok(rule));
+        }
+    }
+
+    #[test]
+    fn test_max_digits() {
+        assert_eq!(max_digits(10), 20);
+        assert_eq!(max_digits(16), 16);
+        assert_eq!(max_digits(2), 64);
+    }
+
+    #[test]
+    fn test_repeated_ids() {
+        assert_eq!(
+            repeated_ids((1, 120), 2, 10),
+            vec![11, 22, 33, 44, 55, 66, 77, 88, 99]
+        );
+        assert_eq!(repeated_ids((1000, 1212), 2, 10), vec![1010, 1111, 1212]);
+        assert_eq!(repeated_ids((100000, 120000), 3, 10), vec![101010, 111111]);
+        assert_eq!(repeated_ids((5, 3), 2, 10), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn test_repeated_ids_wide_range() {
+        assert_eq!(
+            repeated_ids((18446744070000000000, u64::MAX), 2, 10),
+            vec![18446744071844674407]
+        );
+        assert_eq!(
+            repeated_ids((0, u64::MAX), 20, 10),
+            vec![11111111111111111111]
+        );
+    }
+
+    #[test]
+    fn test_repeated_ids_hex() {
+        assert_eq!(
+            repeated_ids((0xab00, 0xadff), 2, 16),
+            vec![0xabab, 0xacac, 0xadad]
+        );
+        assert_eq!(repeated_ids((0, u64::MAX), 16, 16).len(), 15);
+    }
+
+    #[test]
+    fn test_invalid_ids_at_least() {
+        assert_eq!(invalid_ids((95, 115), Rule::AtLeast(2), 10), vec![99, 111]);
+        assert_eq!(
+            invalid_ids((111110, 111112), Rule::AtLeast(2), 10),
+            vec![111111]
+        );
+        assert_eq!(
+            invalid_ids((111110, 222223), Rule::AtLeast(3), 10),
+            vec![
+                111111, 121212, 131313, 141414, 151515, 161616, 171717, 181818, 191919, 202020,
+                212121, 222222
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_ids_unit() {
+        assert_eq!(invalid_ids((1, 1200), Rule::Unit(2), 10), vec![1010, 1111]);
+        assert_eq!(invalid_ids((1, 10), Rule::Unit(1), 10), Vec::<u64>::new());
+        assert_eq!(
+            invalid_ids((100000, 101011), Rule::Unit(2), 10),
+            vec![101010]
+        );
+    }
+
+    #[test]
+    fn test_palindromic_ids() {
+        assert_eq!(
+            palindromic_ids((5, 35), 10),
+            vec![5, 6, 7, 8, 9, 11, 22, 33]
+        );
+        assert_eq!(palindromic_ids((980, 1111), 10), vec![989, 999, 1001, 1111]);
+        assert_eq!(palindromic_ids((0x100, 0x11f), 16), vec![0x101, 0x111]);
+        assert_eq!(palindromic_ids((u64::MAX - 5, u64::MAX), 2), vec![u64::MAX]);
+    }
+
+    #[test]
+    fn test_sum_invalid_ids() {
+        for (range, base) in [((1, 5000), 10), ((95, 222223), 10), ((0, 0xffff), 16)] {
+            for rule in [
+                Rule::Exactly(2),
+                Rule::Exactly(3),
+                Rule::AtLeast(1),
+                Rule::AtLeast(2),
+                Rule::AtLeast(3),
+                Rule::Palindrome,
+                Rule::Unit(2),
+            ] {
+                let expected: u128 = invalid_ids(range, rule, base)
+                    .into_iter()
+                    .map(u128::from)
+                    .sum();
+                assert_eq!(sum_invalid_ids(range, rule, base), expected, "{}", rule);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sum_invalid_ids_wide_range() {
+        assert_eq!(
+            sum_invalid_ids((1, 99999999999999), Rule::Exactly(2), 10),
+            495495500040945040950
+        );
+        // every ID of up to 64 bits is a single bit repeated at least once
+        assert_eq!(
+            sum_invalid_ids((0, u64::MAX), Rule::AtLeast(1), 2),
+            u64::MAX as u128 * (u64::MAX as u128 + 1) / 2
+        );
+    }
+
+    #[test]
+    fn test_palindromic_ids_brute_force() {
+        let brute_force: Vec<u64> = (0..5000u64)
+            .filter(|id| {
+                let digits = id.to_string();
+                *id > 0 && digits.chars().rev().collect::<String>() == digits
+            })
+            .collect();
+        assert_eq!(palindromic_ids((0, 4999), 10), brute_force);
+    }
+}
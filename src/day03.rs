@@ -0,0 +1,386 @@
+use crate::Solution;
+use crate::error::{ParseError, parse_non_empty_lines};
+use num_bigint::BigUint;
+use std::collections::VecDeque;
+
+pub mod prob1;
+pub mod prob2;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u8>>;
+    type Output = u128;
+
+    const EXAMPLE: &'static str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    fn parse(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        parse_non_empty_lines(input, parse_line)
+    }
+
+    fn part1(banks: Vec<Vec<u8>>) -> u128 {
+        u128::from(prob1::find_max_joltage_all(banks))
+    }
+
+    fn part2(banks: Vec<Vec<u8>>) -> Option<u128> {
+        Some(prob2::find_max_joltage_all(banks))
+    }
+}
+
+/// The number of cells the second part turns on in each bank
+pub const JOLTAGE_DIGITS: usize = 12;
+
+pub fn parse_line(line: &str) -> Result<Vec<u8>, ParseError> {
+    line.chars()
+        .enumerate()
+        .map(|(i, char)| {
+            char.to_digit(10)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| ParseError::new(line, i + 1, "a digit"))
+        })
+        .collect()
+}
+
+/// The largest number of digits whose value always fits in a `u64`
+pub const MAX_U64_DIGITS: usize = 19;
+
+/// Choose the indices of the `k` cells of `bank` that form the largest `k`-digit number when read
+/// in order.  A monotonic stack keeps the chosen digits in order, and a smaller digit is dropped
+/// whenever a larger one follows it and there are still cells to spare, so this runs in O(n) no
+/// matter how large `k` is.
+pub fn max_subsequence_indices(bank: &[u8], k: usize) -> Vec<usize> {
+    assert!(
+        k <= bank.len(),
+        "can't pick {} cells from a bank of {}",
+        k,
+        bank.len()
+    );
+    let mut to_drop = bank.len() - k;
+    let mut stack: Vec<usize> = Vec::with_capacity(bank.len());
+    for (i, digit) in bank.iter().enumerate() {
+        while to_drop > 0 && stack.last().is_some_and(|top| bank[*top] < *digit) {
+            stack.pop();
+            to_drop -= 1;
+        }
+        stack.push(i);
+    }
+    // any cells left to drop are the smallest trailing ones
+    stack.truncate(k);
+    stack
+}
+
+/// Find the largest `k`-digit number that can be formed from the cells of `bank` in order, along
+/// with the indices of the cells chosen.  `k` can be at most [`MAX_U64_DIGITS`], above which
+/// [`max_subsequence_big`] is needed.
+pub fn max_subsequence(bank: &[u8], k: usize) -> (u64, Vec<usize>) {
+    assert!(
+        k <= MAX_U64_DIGITS,
+        "a {}-digit joltage doesn't fit in a u64",
+        k
+    );
+    let indices = max_subsequence_indices(bank, k);
+    let value = indices
+        .iter()
+        .fold(0u64, |value, i| value * 10 + bank[*i] as u64);
+    (value, indices)
+}
+
+/// Find the largest `k`-digit number that can be formed from the cells of `bank` in order, as an
+/// arbitrary-precision integer so that `k` can be anything up to the length of the bank, along
+/// with the indices of the cells chosen
+pub fn max_subsequence_big(bank: &[u8], k: usize) -> (BigUint, Vec<usize>) {
+    let indices = max_subsequence_indices(bank, k);
+    (joltage(bank, &indices), indices)
+}
+
+/// The number formed by reading the cells of `bank` at `indices` in order
+pub fn joltage(bank: &[u8], indices: &[usize]) -> BigUint {
+    let digits: Vec<u8> = indices.iter().map(|i| bank[*i]).collect();
+    BigUint::from_radix_be(&digits, 10).expect("cells are decimal digits")
+}
+
+/// Sum the largest `k`-digit joltage of every bank exactly, however large `k` is and however many
+/// banks there are
+pub fn total_max_joltage(banks: &[Vec<u8>], k: usize) -> BigUint {
+    banks
+        .iter()
+        .map(|bank| max_subsequence_big(bank, k).0)
+        .sum()
+}
+
+/// Find the smallest `k`-digit number without a leading zero that can be formed from the cells of
+/// `bank` in order, along with the indices of the cells chosen, or `None` if every cell that could
+/// come first is a zero.  The first digit is the smallest non-zero one that leaves enough cells
+/// after it, and the rest come from a monotonic stack like [`max_subsequence_indices`] with the
+/// comparison flipped.
+pub fn min_subsequence(bank: &[u8], k: usize) -> Option<(BigUint, Vec<usize>)> {
+    assert!(
+        k <= bank.len(),
+        "can't pick {} cells from a bank of {}",
+        k,
+        bank.len()
+    );
+    if k == 0 {
+        return Some((BigUint::ZERO, Vec::new()));
+    }
+    // the leftmost of the smallest digits leaves the most cells to choose the rest from
+    let first = (0..=bank.len() - k)
+        .filter(|i| bank[*i] != 0)
+        .min_by_key(|i| bank[*i])?;
+
+    let rest = &bank[first + 1..];
+    let mut to_drop = rest.len() - (k - 1);
+    let mut stack: Vec<usize> = Vec::with_capacity(rest.len() + 1);
+    stack.push(first);
+    for (i, digit) in rest
+        .iter()
+        .enumerate()
+        .map(|(i, digit)| (first + 1 + i, digit))
+    {
+        while to_drop > 0 && stack.len() > 1 && stack.last().is_some_and(|top| bank[*top] > *digit)
+        {
+            stack.pop();
+            to_drop -= 1;
+        }
+        stack.push(i);
+    }
+    stack.truncate(k);
+    Some((joltage(bank, &stack), stack))
+}
+
+/// Choose the indices of the `k` cells of `bank` that form the largest `k`-digit number when every
+/// chosen cell must be at least `gap` positions after the one before, or `None` if `k` cells that
+/// far apart don't fit in the bank.  Each digit is the leftmost largest in the window that still
+/// leaves room for the rest, and since both ends of that window only move right, a monotonic
+/// queue finds it in O(n) overall.
+pub fn max_spaced_subsequence_indices(bank: &[u8], k: usize, gap: usize) -> Option<Vec<usize>> {
+    assert!(gap > 0, "chosen cells must be at least one position apart");
+    if k == 0 {
+        return Some(Vec::new());
+    }
+    if (k - 1) * gap >= bank.len() {
+        return None;
+    }
+
+    let mut indices: Vec<usize> = Vec::with_capacity(k);
+    // candidate indices in order, with non-increasing digits since only smaller digits are popped,
+    // so that the front is the leftmost largest
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    let mut next_i = 0;
+    let mut start = 0;
+    for remaining in (0..k).rev() {
+        let end = bank.len() - 1 - remaining * gap;
+        while next_i <= end {
+            while queue.back().is_some_and(|back| bank[*back] < bank[next_i]) {
+                queue.pop_back();
+            }
+            queue.push_back(next_i);
+            next_i += 1;
+        }
+        while queue.front().is_some_and(|front| *front < start) {
+            queue.pop_front();
+        }
+
+        let chosen = queue.pop_front().expect("the window always holds a cell");
+        indices.push(chosen);
+        start = chosen + gap;
+    }
+    Some(indices)
+}
+
+/// Choose the index of the leftmost largest cell in each consecutive segment of `segment_len`
+/// cells, the last of which may be shorter.  With one pick per segment, each segment decides one
+/// digit of the result on its own, so this is the largest number such a selection can form.
+pub fn max_segmented_indices(bank: &[u8], segment_len: usize) -> Vec<usize> {
+    assert!(segment_len > 0, "segments must hold at least one cell");
+    bank.chunks(segment_len)
+        .enumerate()
+        .map(|(segment_i, segment)| {
+            let max_digit = segment.iter().max().expect("chunks are never empty");
+            let offset = segment
+                .iter()
+                .position(|digit| digit == max_digit)
+                .expect("the max is in the segment");
+            segment_i * segment_len + offset
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("987654321111111"),
+            Ok(vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1])
+        )
+    }
+
+    #[test]
+    fn test_parse_line_bad_digit() {
+        assert_eq!(
+            parse_line("98765x321"),
+            Err(ParseError::new("98765x321", 6, "a digit"))
+        )
+    }
+
+    #[test]
+    fn test_short_banks() {
+        // a bank too short for a part adds nothing to that part's total
+        let banks = Day03::parse("987654321111111\n1\n12345\n").unwrap();
+        assert_eq!(Day03::part1(banks.clone()), 98 + 45);
+        assert_eq!(Day03::part2(banks), Some(987654321111));
+    }
+
+    #[test]
+    fn test_max_subsequence() {
+        assert_eq!(
+            max_subsequence(&[8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1], 2),
+            (92, vec![6, 11])
+        );
+        assert_eq!(
+            max_subsequence(&[2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8], 12),
+            (434234234278, vec![2, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14])
+        );
+        assert_eq!(max_subsequence(&[1, 2, 3], 3), (123, vec![0, 1, 2]));
+        assert_eq!(max_subsequence(&[1, 2, 3], 0), (0, vec![]));
+    }
+
+    #[test]
+    fn test_max_subsequence_big() {
+        let bank: Vec<u8> = "9876543210"
+            .repeat(3)
+            .bytes()
+            .map(|byte| byte - b'0')
+            .collect();
+        let (value, indices) = max_subsequence_big(&bank, 25);
+        assert_eq!(value.to_string(), "9876598765432109876543210");
+        assert_eq!(indices.len(), 25);
+        assert_eq!(max_subsequence_big(&[1, 2], 0).0, BigUint::ZERO);
+    }
+
+    #[test]
+    fn test_total_max_joltage() {
+        let banks: Vec<Vec<u8>> = vec![vec![9; 40]; 3000];
+        // 3000 * (10^40 - 1) = 3 * 10^43 - 3000
+        assert_eq!(
+            total_max_joltage(&banks, 40).to_string(),
+            format!("2{}7000", "9".repeat(39))
+        );
+        assert_eq!(
+            total_max_joltage(&Day03::parse(Day03::EXAMPLE).unwrap(), 12),
+            BigUint::from(3121910778619u64)
+        );
+    }
+
+    #[test]
+    fn test_min_subsequence() {
+        let bank = parse_line("3020105").unwrap();
+        assert_eq!(
+            min_subsequence(&bank, 4),
+            Some((BigUint::from(2005u32), vec![2, 3, 5, 6]))
+        );
+        assert_eq!(min_subsequence(&[0, 0, 1, 0], 3), None);
+        assert_eq!(
+            min_subsequence(&[0, 1, 0, 0], 3),
+            Some((BigUint::from(100u32), vec![1, 2, 3]))
+        );
+        assert_eq!(min_subsequence(&[5], 0), Some((BigUint::ZERO, vec![])));
+    }
+
+    #[test]
+    fn test_max_spaced_subsequence_indices() {
+        let bank = parse_line("818181911112111").unwrap();
+        assert_eq!(
+            max_spaced_subsequence_indices(&bank, 2, 1),
+            Some(max_subsequence_indices(&bank, 2))
+        );
+        let indices = max_spaced_subsequence_indices(&bank, 3, 4).unwrap();
+        assert_eq!(indices, vec![6, 10, 14]);
+        assert_eq!(joltage(&bank, &indices), BigUint::from(911u32));
+        assert_eq!(max_spaced_subsequence_indices(&bank, 4, 5), None);
+        assert_eq!(
+            max_spaced_subsequence_indices(&bank, 3, 7),
+            Some(vec![0, 7, 14])
+        );
+    }
+
+    #[test]
+    fn test_max_spaced_subsequence_indices_brute_force() {
+        let bank = parse_line("271828182845").unwrap();
+        // every selection of cells, as a bitmask
+        let selections: Vec<Vec<usize>> = (1..1u32 << bank.len())
+            .map(|mask| (0..bank.len()).filter(|i| mask & (1 << i) != 0).collect())
+            .collect();
+        for gap in 1..5 {
+            for k in 1..=(bank.len() - 1) / gap + 1 {
+                let best = selections
+                    .iter()
+                    .filter(|selection| selection.len() == k)
+                    .filter(|selection| selection.windows(2).all(|pair| pair[1] - pair[0] >= gap))
+                    .map(|selection| joltage(&bank, selection))
+                    .max()
+                    .unwrap();
+                let indices = max_spaced_subsequence_indices(&bank, k, gap).unwrap();
+                assert_eq!(joltage(&bank, &indices), best, "k = {}, gap = {}", k, gap);
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_segmented_indices() {
+        let bank = parse_line("818181911112111").unwrap();
+        let indices = max_segmented_indices(&bank, 4);
+        assert_eq!(indices, vec![0, 6, 11, 12]);
+        assert_eq!(joltage(&bank, &indices), BigUint::from(8921u32));
+        assert_eq!(max_segmented_indices(&bank, 1).len(), bank.len());
+    }
+
+    /// Pick each digit as the largest that still leaves enough cells for the rest, which is
+    /// O(n·k)
+    fn max_subsequence_digits_greedy(bank: &[u8], k: usize) -> Vec<u8> {
+        let mut start_i = 0;
+        (0..k)
+            .rev()
+            .map(|remaining| {
+                let window = &bank[start_i..bank.len() - remaining];
+                let max_digit = *window.iter().max().unwrap();
+                start_i += window.iter().position(|digit| *digit == max_digit).unwrap() + 1;
+                max_digit
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_max_subsequence_indices_long_bank() {
+        // a deterministic jumble of digits
+        let bank: Vec<u8> = (0..5000u64)
+            .map(|i| (i * i * 7 + i * 13) % 10)
+            .map(|d| d as u8)
+            .collect();
+        for k in [1, 50, 300, 4999, 5000] {
+            let indices = max_subsequence_indices(&bank, k);
+            assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+            assert_eq!(
+                indices.iter().map(|i| bank[*i]).collect::<Vec<u8>>(),
+                max_subsequence_digits_greedy(&bank, k)
+            );
+        }
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day03::part1(Day03::parse(Day03::EXAMPLE).unwrap()), 357);
+        assert_eq!(
+            Day03::part2(Day03::parse(Day03::EXAMPLE_PART2).unwrap()),
+            Some(3121910778619)
+        );
+    }
+}
@@ -1,57 +1,24 @@
-fn main() {
-    let banks = std::fs::read_to_string("src/bin/day03.txt")
-        .map(|file| {
-            file.lines()
-                .filter(|line| !line.is_empty())
-                .map(parse_line)
-                .collect()
-        })
-        .expect("Unable to open file");
-    println!("{}", find_max_joltage_all(banks))
-}
-
-fn parse_line(line: &str) -> Vec<u8> {
-    line.chars()
-        .map(|char| {
-            char.to_string()
-                .parse::<u8>()
-                .expect("unable to parse number")
-        })
-        .collect()
-}
+use super::max_subsequence;
 
-fn find_max_joltage_all(banks: Vec<Vec<u8>>) -> u16 {
+pub fn find_max_joltage_all(banks: Vec<Vec<u8>>) -> u64 {
     banks
         .into_iter()
-        .map(|bank| find_max_joltage_bank(bank) as u16)
+        .map(|bank| find_max_joltage_bank(bank) as u64)
         .sum()
 }
 
-fn find_max_joltage_bank(bank: Vec<u8>) -> u8 {
-    let mut max = 0u8;
-    for i in 0..bank.len() - 1 {
-        for j in i + 1..bank.len() {
-            let val = bank[i] * 10 + bank[j];
-            if val > max {
-                max = val;
-            }
-        }
+/// The largest joltage of two cells of a bank, or 0 if the bank has fewer than two cells
+pub fn find_max_joltage_bank(bank: Vec<u8>) -> u8 {
+    if bank.len() < 2 {
+        return 0;
     }
-    max
+    max_subsequence(&bank, 2).0 as u8
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
-    #[test]
-    fn test_parse_line() {
-        assert_eq!(
-            parse_line("987654321111111"),
-            vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1]
-        )
-    }
-
     #[test]
     fn test_find_max_joltage_bank_1() {
         assert_eq!(
@@ -84,6 +51,12 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_find_max_joltage_bank_short() {
+        assert_eq!(find_max_joltage_bank(vec![7]), 0);
+        assert_eq!(find_max_joltage_bank(vec![]), 0);
+    }
+
     #[test]
     fn test_find_max_joltage_all() {
         assert_eq!(
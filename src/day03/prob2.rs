@@ -0,0 +1,73 @@
+use super::{JOLTAGE_DIGITS, max_subsequence};
+
+/// Sum the joltage of every bank exactly.  Each joltage is below 10^12, so the total fits in a
+/// `u128` for any number of banks that could be held in memory.
+pub fn find_max_joltage_all(banks: Vec<Vec<u8>>) -> u128 {
+    banks
+        .into_iter()
+        .map(|bank| u128::from(find_max_joltage_bank(bank)))
+        .sum()
+}
+
+/// The largest joltage of twelve cells of a bank, or 0 if the bank has fewer than twelve cells
+pub fn find_max_joltage_bank(bank: Vec<u8>) -> u64 {
+    if bank.len() < JOLTAGE_DIGITS {
+        return 0;
+    }
+    max_subsequence(&bank, JOLTAGE_DIGITS).0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_max_joltage_bank_1() {
+        assert_eq!(
+            find_max_joltage_bank(vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1]),
+            987654321111
+        )
+    }
+
+    #[test]
+    fn test_find_max_joltage_bank_2() {
+        assert_eq!(
+            find_max_joltage_bank(vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9]),
+            811111111119
+        )
+    }
+
+    #[test]
+    fn test_find_max_joltage_bank_3() {
+        assert_eq!(
+            find_max_joltage_bank(vec![2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8]),
+            434234234278
+        )
+    }
+
+    #[test]
+    fn test_find_max_joltage_bank_4() {
+        assert_eq!(
+            find_max_joltage_bank(vec![8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1]),
+            888911112111
+        )
+    }
+
+    #[test]
+    fn test_find_max_joltage_bank_short() {
+        assert_eq!(find_max_joltage_bank(vec![9; 11]), 0);
+    }
+
+    #[test]
+    fn test_find_max_joltage_all() {
+        assert_eq!(
+            find_max_joltage_all(vec![
+                vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+                vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+                vec![2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+                vec![8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1]
+            ]),
+            3121910778619
+        )
+    }
+}
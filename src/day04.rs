@@ -0,0 +1,107 @@
+use crate::Solution;
+use crate::automaton::{Automaton, Boundary, Neighborhood, Rule};
+use crate::bit_grid::BitGrid;
+use crate::error::ParseError;
+use crate::grid::Grid;
+
+pub mod peel;
+pub mod prob1;
+pub mod prob2;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = BitGrid;
+    type Output = usize;
+
+    const EXAMPLE: &'static str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    fn parse(input: &str) -> Result<BitGrid, ParseError> {
+        BitGrid::parse(input, parse_cell, "'@' or '.'")
+    }
+
+    fn part1(map: BitGrid) -> usize {
+        prob1::count_accessible_rolls(&map)
+    }
+
+    fn part2(mut map: BitGrid) -> Option<usize> {
+        Some(prob2::remove_rolls_packed(&mut map).iter().sum())
+    }
+}
+
+/// A roll of paper is `@`, an empty space is `.`
+pub fn parse_cell(char: char) -> Option<bool> {
+    match char {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+/// The forklifts as an automaton: a roll with fewer than four rolls among its eight neighbors
+/// can be reached and is removed, and nothing new ever appears
+pub fn forklift() -> Automaton {
+    Automaton::new(Neighborhood::Moore, Boundary::Bounded, Rule::new([], 4..=8))
+}
+
+/// The rolls with fewer than four rolls among their eight neighbors, found 64 cells at a time
+pub fn accessible_rolls(map: &BitGrid) -> BitGrid {
+    let mut accessible = map.fewer_neighbors(4);
+    accessible.retain_all(map);
+    accessible
+}
+
+/// Count the rolls among the eight neighbors of a position
+pub fn count_neighbors(map: &Grid<bool>, pos: (usize, usize)) -> usize {
+    forklift().count_neighbors(map, pos)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Day04::parse("..@@.@@@@.\n").map(|map| map.to_grid()),
+            Ok(Grid::from_rows(vec![vec![
+                false, false, true, true, false, true, true, true, true, false
+            ]]))
+        )
+    }
+
+    #[test]
+    fn test_parse_bad_char() {
+        assert_eq!(
+            Day04::parse("....\n..@#"),
+            Err(ParseError::new("..@#", 4, "'@' or '.'").at_line(2))
+        )
+    }
+
+    #[test]
+    fn test_count_neighbors() {
+        let map = Day04::parse(Day04::EXAMPLE).unwrap().to_grid();
+        assert_eq!(count_neighbors(&map, (0, 0)), 2);
+        assert_eq!(count_neighbors(&map, (4, 4)), 8);
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day04::part1(Day04::parse(Day04::EXAMPLE).unwrap()), 13);
+        assert_eq!(
+            Day04::part2(Day04::parse(Day04::EXAMPLE_PART2).unwrap()),
+            Some(43)
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::grid::Grid;
+use std::io::{self, Write};
+
+/// The chars for the first rounds of removal, after which every round is drawn as `+`
+const ROUND_CHARS: &str = "123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The colors of empty spaces and of rolls that are never removed
+const EMPTY_COLOR: [u8; 3] = [255, 255, 255];
+const REMAINING_COLOR: [u8; 3] = [0, 0, 0];
+
+/// The colors of the first and last rounds of removal, with the rounds between blended from one
+/// to the other
+const FIRST_ROUND_COLOR: [u8; 3] = [255, 214, 64];
+const LAST_ROUND_COLOR: [u8; 3] = [160, 16, 48];
+
+/// The char for a roll removed in round `depth`
+fn round_char(depth: u32) -> char {
+    ROUND_CHARS.chars().nth(depth as usize - 1).unwrap_or('+')
+}
+
+/// Draw the round each roll was removed in as a char per cell, with `@` for the rolls in
+/// `remaining` that are never removed and `.` for empty spaces
+pub fn render(depths: &Grid<Option<u32>>, remaining: &Grid<bool>) -> String {
+    let chars = Grid::from_rows(
+        depths
+            .positions()
+            .map(|pos| match depths[pos] {
+                Some(depth) => round_char(depth),
+                None if remaining[pos] => '@',
+                None => '.',
+            })
+            .collect::<Vec<char>>()
+            .chunks(depths.width().max(1))
+            .map(|row| row.to_vec())
+            .collect(),
+    );
+    chars.to_string()
+}
+
+/// The color of a cell, shading removed rolls from light to dark by how late they were removed
+fn color(depth: Option<u32>, remaining: bool, max_depth: u32) -> [u8; 3] {
+    match depth {
+        Some(depth) => {
+            let t = if max_depth > 1 {
+                (depth - 1) as f64 / (max_depth - 1) as f64
+            } else {
+                0.0
+            };
+            let mut color = [0; 3];
+            for (i, channel) in color.iter_mut().enumerate() {
+                let (first, last) = (FIRST_ROUND_COLOR[i] as f64, LAST_ROUND_COLOR[i] as f64);
+                *channel = (first + (last - first) * t).round() as u8;
+            }
+            color
+        }
+        None if remaining => REMAINING_COLOR,
+        None => EMPTY_COLOR,
+    }
+}
+
+/// The RGB pixels of the heat map, row by row, with each cell drawn as a `scale` by `scale`
+/// square.  Returns the width and height in pixels along with the pixels.
+pub fn pixels(
+    depths: &Grid<Option<u32>>,
+    remaining: &Grid<bool>,
+    scale: usize,
+) -> (usize, usize, Vec<u8>) {
+    let max_depth = depths
+        .iter()
+        .filter_map(|(_, depth)| *depth)
+        .max()
+        .unwrap_or(0);
+    let (width, height) = (depths.width() * scale, depths.height() * scale);
+    let mut pixels: Vec<u8> = Vec::with_capacity(width * height * 3);
+    for row in 0..height {
+        for column in 0..width {
+            let pos = (row / scale, column / scale);
+            pixels.extend(color(depths[pos], remaining[pos], max_depth));
+        }
+    }
+    (width, height, pixels)
+}
+
+/// Write the heat map as a binary PPM image
+pub fn write_ppm(
+    out: &mut impl Write,
+    depths: &Grid<Option<u32>>,
+    remaining: &Grid<bool>,
+    scale: usize,
+) -> io::Result<()> {
+    let (width, height, pixels) = pixels(depths, remaining, scale);
+    write!(out, "P6\n{} {}\n255\n", width, height)?;
+    out.write_all(&pixels)
+}
+
+/// Write the heat map as a PNG image
+pub fn write_png(
+    out: &mut impl Write,
+    depths: &Grid<Option<u32>>,
+    remaining: &Grid<bool>,
+    scale: usize,
+) -> io::Result<()> {
+    let (width, height, pixels) = pixels(depths, remaining, scale);
+    let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer.write_image_data(&pixels).map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day04::prob2::remove_rolls;
+    use crate::day04::{Day04, parse_cell};
+
+    fn peel() -> (Grid<Option<u32>>, Grid<bool>) {
+        let mut map =
+            Grid::parse("@@@@@\n@@@@@\n@@@@@\n@@@.@\n", parse_cell, "'@' or '.'").unwrap();
+        (remove_rolls(&mut map), map)
+    }
+
+    #[test]
+    fn test_round_char() {
+        assert_eq!(round_char(1), '1');
+        assert_eq!(round_char(10), 'a');
+        assert_eq!(round_char(61), 'Z');
+        assert_eq!(round_char(62), '+');
+    }
+
+    #[test]
+    fn test_render() {
+        let (depths, remaining) = peel();
+        assert_eq!(render(&depths, &remaining), "1@@41\n@@@@3\n@@@@2\n1@@.1\n");
+    }
+
+    #[test]
+    fn test_render_example() {
+        use crate::Solution;
+        let mut map = Day04::parse(Day04::EXAMPLE).unwrap().to_grid();
+        let depths = remove_rolls(&mut map);
+        let rendered = render(&depths, &map);
+        assert_eq!(rendered.lines().next(), Some("..11.1121."));
+        assert_eq!(
+            rendered.chars().filter(|char| *char == '@').count(),
+            71 - 43
+        );
+    }
+
+    #[test]
+    fn test_pixels() {
+        let (depths, remaining) = peel();
+        let (width, height, pixels) = pixels(&depths, &remaining, 2);
+        assert_eq!((width, height), (10, 8));
+        assert_eq!(pixels.len(), 10 * 8 * 3);
+        let pixel = |row: usize, column: usize| &pixels[(row * width + column) * 3..][..3];
+        assert_eq!(pixel(0, 0), FIRST_ROUND_COLOR);
+        assert_eq!(pixel(1, 2), REMAINING_COLOR);
+        assert_eq!(pixel(1, 7), LAST_ROUND_COLOR);
+        assert_eq!(pixel(7, 6), EMPTY_COLOR);
+        // the rounds between are blended
+        assert_eq!(pixel(2, 8), [192, 82, 53]);
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let (depths, remaining) = peel();
+        let mut out: Vec<u8> = Vec::new();
+        write_ppm(&mut out, &depths, &remaining, 1).unwrap();
+        assert!(out.starts_with(b"P6\n5 4\n255\n"));
+        assert_eq!(out.len(), "P6\n5 4\n255\n".len() + 5 * 4 * 3);
+    }
+
+    #[test]
+    fn test_write_png() {
+        let (depths, remaining) = peel();
+        let mut out: Vec<u8> = Vec::new();
+        write_png(&mut out, &depths, &remaining, 3).unwrap();
+
+        let mut reader = png::Decoder::new(io::Cursor::new(out)).read_info().unwrap();
+        let mut decoded = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut decoded).unwrap();
+        assert_eq!((info.width, info.height), (15, 12));
+        assert_eq!(decoded, pixels(&depths, &remaining, 3).2);
+    }
+}
@@ -0,0 +1,33 @@
+use super::accessible_rolls;
+use crate::bit_grid::BitGrid;
+
+pub fn count_accessible_rolls(map: &BitGrid) -> usize {
+    accessible_rolls(map).count_ones()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn test_count_accessible_rolls() {
+        let f = false;
+        let t = true;
+        assert_eq!(
+            count_accessible_rolls(&BitGrid::from(&Grid::from_rows(vec![
+                vec![f, f, t, t, f, t, t, t, t, f],
+                vec![t, t, t, f, t, f, t, f, t, t],
+                vec![t, t, t, t, t, f, t, f, t, t],
+                vec![t, f, t, t, t, t, f, f, t, f],
+                vec![t, t, f, t, t, t, t, f, t, t],
+                vec![f, t, t, t, t, t, t, t, f, t],
+                vec![f, t, f, t, f, t, f, t, t, t],
+                vec![t, f, t, t, t, f, t, t, t, t],
+                vec![f, t, t, t, t, t, t, t, t, f],
+                vec![t, f, t, f, t, t, t, f, t, f]
+            ]))),
+            13
+        )
+    }
+}
@@ -0,0 +1,217 @@
+use super::{accessible_rolls, forklift};
+use crate::bit_grid::BitGrid;
+use crate::grid::Grid;
+
+/// Remove accessible rolls round by round until none are left, returning the round in which each
+/// roll was removed, counting from 1, or `None` for empty spaces and rolls that are never removed.
+/// Rather than rescanning the map every round, the neighbor count of every roll is kept up to
+/// date as its neighbors are removed, and only the rolls whose count has just dropped below the
+/// threshold are removed in the next round.  This holds a byte of neighbor count and eight bytes
+/// of round for every cell on top of the map, so [`remove_rolls_packed`], which works a bit per
+/// cell, is better for counting the rolls removed from large maps.
+pub fn remove_rolls(map: &mut Grid<bool>) -> Grid<Option<u32>> {
+    let forklift = forklift();
+    // a roll has at most eight neighbors, so its count fits in a byte
+    let mut counts = map.map(|_| 0u8);
+    for pos in map.positions().filter(|pos| map[*pos]) {
+        counts[pos] = forklift.count_neighbors(map, pos) as u8;
+    }
+
+    let mut depths = map.map(|_| None);
+    let mut depth: u32 = 0;
+    let mut round = forklift.changes(map);
+    while !round.is_empty() {
+        depth += 1;
+        log::debug!("Removed {} rolls", round.len());
+        // remove the whole round first, so that rolls removed together don't queue each other
+        for pos in round.iter() {
+            map[*pos] = false;
+            depths[*pos] = Some(depth);
+        }
+
+        let mut next_round: Vec<(usize, usize)> = Vec::new();
+        for pos in round.iter() {
+            for neighbor in forklift.neighbors(map, *pos) {
+                if map[neighbor] {
+                    counts[neighbor] -= 1;
+                    // each roll crosses the threshold once, so is only queued once
+                    if counts[neighbor] == 3 {
+                        next_round.push(neighbor);
+                    }
+                }
+            }
+        }
+        round = next_round;
+    }
+    depths
+}
+
+/// The number of rolls removed in each round, given the round each roll was removed in
+pub fn removal_rounds(depths: &Grid<Option<u32>>) -> Vec<usize> {
+    let mut rounds: Vec<usize> = Vec::new();
+    for depth in depths.iter().filter_map(|(_, depth)| *depth) {
+        let round = depth as usize - 1;
+        if round >= rounds.len() {
+            rounds.resize(round + 1, 0);
+        }
+        rounds[round] += 1;
+    }
+    rounds
+}
+
+/// The number of rolls removed in every round
+pub fn count_removed(depths: &Grid<Option<u32>>) -> usize {
+    depths.iter().filter(|(_, depth)| depth.is_some()).count()
+}
+
+/// Remove accessible rolls round by round until none are left, returning the number removed in
+/// each round.  Each round checks 64 cells at a time, and only in the rows next to a row that
+/// changed in the round before.
+pub fn remove_rolls_packed(map: &mut BitGrid) -> Vec<usize> {
+    let mut rounds: Vec<usize> = Vec::new();
+    let words_per_row = map.width().div_ceil(64);
+    let mut dirty = vec![true; map.height()];
+    let mut accessible = vec![0u64; words_per_row];
+    // the rows with rolls to remove this round, and their accessible rolls a row of words each
+    let mut removal_rows: Vec<usize> = Vec::new();
+    let mut removal_words: Vec<u64> = Vec::new();
+    loop {
+        // find the whole round before removing any of it
+        removal_rows.clear();
+        removal_words.clear();
+        for row in (0..map.height()).filter(|row| dirty[*row]) {
+            map.fewer_neighbors_in_row(row, 4, &mut accessible);
+            for (accessible, rolls) in accessible.iter_mut().zip(map.row_words(row)) {
+                *accessible &= rolls;
+            }
+            if accessible.iter().any(|word| *word != 0) {
+                removal_rows.push(row);
+                removal_words.extend_from_slice(&accessible);
+            }
+        }
+        if removal_rows.is_empty() {
+            break;
+        }
+
+        dirty.fill(false);
+        let mut removed: usize = 0;
+        for (row, accessible) in removal_rows
+            .iter()
+            .zip(removal_words.chunks_exact(words_per_row))
+        {
+            for (rolls, accessible) in map.row_words_mut(*row).iter_mut().zip(accessible) {
+                *rolls &= !accessible;
+                removed += accessible.count_ones() as usize;
+            }
+            let last_row = (row + 1).min(map.height() - 1);
+            dirty[row.saturating_sub(1)..=last_row].fill(true);
+        }
+        log::debug!("Removed {} rolls", removed);
+        rounds.push(removed);
+    }
+    rounds
+}
+
+pub fn find_accessible_rolls(map: &BitGrid) -> Vec<(usize, usize)> {
+    accessible_rolls(map).ones().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Solution;
+    use crate::day04::Day04;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_find_accessible_rolls() {
+        let f = false;
+        let t = true;
+        assert_eq!(
+            find_accessible_rolls(&BitGrid::from(&Grid::from_rows(vec![
+                vec![f, f, t, t, f, t, t, t, t, f],
+                vec![t, t, t, f, t, f, t, f, t, t],
+                vec![t, t, t, t, t, f, t, f, t, t],
+                vec![t, f, t, t, t, t, f, f, t, f],
+                vec![t, t, f, t, t, t, t, f, t, t],
+                vec![f, t, t, t, t, t, t, t, f, t],
+                vec![f, t, f, t, f, t, f, t, t, t],
+                vec![t, f, t, t, t, f, t, t, t, t],
+                vec![f, t, t, t, t, t, t, t, t, f],
+                vec![t, f, t, f, t, t, t, f, t, f]
+            ]))),
+            vec![
+                (0, 2),
+                (0, 3),
+                (0, 5),
+                (0, 6),
+                (0, 8),
+                (1, 0),
+                (2, 6),
+                (4, 0),
+                (4, 9),
+                (7, 0),
+                (9, 0),
+                (9, 2),
+                (9, 8)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_remove_rolls() {
+        let f = false;
+        let t = true;
+        assert_eq!(
+            count_removed(&remove_rolls(&mut Grid::from_rows(vec![
+                vec![f, f, t, t, f, t, t, t, t, f],
+                vec![t, t, t, f, t, f, t, f, t, t],
+                vec![t, t, t, t, t, f, t, f, t, t],
+                vec![t, f, t, t, t, t, f, f, t, f],
+                vec![t, t, f, t, t, t, t, f, t, t],
+                vec![f, t, t, t, t, t, t, t, f, t],
+                vec![f, t, f, t, f, t, f, t, t, t],
+                vec![t, f, t, t, t, f, t, t, t, t],
+                vec![f, t, t, t, t, t, t, t, t, f],
+                vec![t, f, t, f, t, t, t, f, t, f]
+            ]))),
+            43
+        )
+    }
+
+    #[test]
+    fn test_removal_rounds() {
+        let mut packed = Day04::parse(Day04::EXAMPLE).unwrap();
+        let mut map = packed.to_grid();
+        let rounds = forklift().run_until_stable(&mut map.clone(), usize::MAX);
+        assert_eq!(remove_rolls_packed(&mut packed), rounds);
+        assert_eq!(
+            packed.to_grid().iter().filter(|(_, roll)| **roll).count(),
+            71 - 43
+        );
+        let depths = remove_rolls(&mut map);
+        assert_eq!(removal_rounds(&depths), rounds);
+        assert_eq!(depths[(0, 2)], Some(1));
+        assert_eq!(depths[(4, 4)], None);
+        assert_eq!(rounds, vec![13, 12, 7, 5, 2, 1, 1, 1, 1]);
+        assert_eq!(map.iter().filter(|(_, roll)| **roll).count(), 71 - 43);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_removal_rounds_match_automaton(
+            rows in (1..80usize, 1..12usize).prop_flat_map(|(width, height)| {
+                prop::collection::vec(prop::collection::vec(any::<bool>(), width), height)
+            }),
+        ) {
+            let mut map = Grid::from_rows(rows);
+            let mut packed = BitGrid::from(&map);
+            let mut expected = map.clone();
+            let rounds = forklift().run_until_stable(&mut expected, usize::MAX);
+            prop_assert_eq!(removal_rounds(&remove_rolls(&mut map)), rounds.clone());
+            prop_assert_eq!(map, expected.clone());
+            prop_assert_eq!(remove_rolls_packed(&mut packed), rounds);
+            prop_assert_eq!(packed.to_grid(), expected);
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use crate::Solution;
+use crate::error::{ParseError, parse_number, parse_numbers};
+use crate::interval_set::IntervalSet;
+
+pub mod prob1;
+pub mod prob2;
+pub mod provenance;
+pub mod report;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Ims;
+    type Output = u128;
+
+    const EXAMPLE: &'static str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    fn parse(input: &str) -> Result<Ims, ParseError> {
+        parse_lines(input.lines().collect())
+    }
+
+    fn part1(ims: Ims) -> u128 {
+        prob1::find_fresh(ims) as u128
+    }
+
+    fn part2(ims: Ims) -> Option<u128> {
+        Some(prob2::find_fresh(ims))
+    }
+}
+
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct Ims {
+    pub fresh: Vec<(u64, u64)>,
+    /// The line number of each fresh range in the input
+    pub fresh_lines: Vec<usize>,
+    /// Ranges of IDs that aren't fresh even if a fresh range covers them
+    pub excluded: Vec<(u64, u64)>,
+    pub available: Vec<u64>,
+}
+
+/// Parse the fresh ranges, then after a blank line the available IDs.  Besides `a-b`, a fresh
+/// range can be a single ID `a` or run from `a` to the largest ID as `a-`, and any of these
+/// prefixed with `!` excludes those IDs instead.  Lines starting with `#` are comments.
+pub fn parse_lines(lines: Vec<&str>) -> Result<Ims, ParseError> {
+    let mut ims = Ims::default();
+    let mut in_fresh = true;
+
+    for (i, line) in lines.into_iter().enumerate() {
+        if line.starts_with("#") {
+            continue;
+        } else if line.is_empty() {
+            in_fresh = false;
+        } else if in_fresh {
+            match line.strip_prefix("!") {
+                Some(entry) => {
+                    let range = parse_range(line, entry).map_err(|err| err.at_line(i + 1))?;
+                    ims.excluded.push(range);
+                }
+                None => {
+                    let range = parse_range(line, line).map_err(|err| err.at_line(i + 1))?;
+                    ims.fresh.push(range);
+                    ims.fresh_lines.push(i + 1);
+                }
+            }
+        } else {
+            let item: u64 = parse_number(line, line).map_err(|err| err.at_line(i + 1))?;
+            ims.available.push(item);
+        }
+    }
+
+    Ok(ims)
+}
+
+/// Parse `entry`, which must be a slice of the line `text`, as a range `a-b`, an open-ended range
+/// `a-` or a single ID `a`
+fn parse_range(text: &str, entry: &str) -> Result<(u64, u64), ParseError> {
+    match entry.split_once("-") {
+        Some((start, "")) => Ok((parse_number(text, start)?, u64::MAX)),
+        Some(_) => {
+            let [start, end] = parse_numbers(text, entry, "-")?;
+            Ok((start, end))
+        }
+        None => {
+            let id = parse_number(text, entry)?;
+            Ok((id, id))
+        }
+    }
+}
+
+/// take the list of ranges supplied and merge all overlapping ranges into a set of fresh IDs, which
+/// keeps a minimal list of ranges sorted by starting value to enable efficient binary searches
+pub fn condense_ranges(ranges: Vec<(u64, u64)>) -> IntervalSet<u64> {
+    let orig_len = ranges.len();
+    let condensed: IntervalSet<u64> = ranges.into_iter().collect();
+    log::debug!(
+        "Orig ranges: {}, condensed ranges: {}",
+        orig_len,
+        condensed.ranges().len()
+    );
+    condensed
+}
+
+/// The fresh IDs: the condensed fresh ranges less the excluded ranges
+pub fn fresh_ids(fresh: Vec<(u64, u64)>, excluded: &[(u64, u64)]) -> IntervalSet<u64> {
+    let excluded: IntervalSet<u64> = excluded.iter().copied().collect();
+    condense_ranges(fresh).difference(&excluded)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::interval_set::strategy::{edge_ranges, edge_values};
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines(vec![
+                "3-5", "10-14", "16-20", "12-18", "", "1", "5", "8", "11", "17", "32",
+            ]),
+            Ok(Ims {
+                fresh: vec![(3, 5), (10, 14), (16, 20), (12, 18)],
+                fresh_lines: vec![1, 2, 3, 4],
+                excluded: vec![],
+                available: vec![1, 5, 8, 11, 17, 32]
+            })
+        )
+    }
+
+    #[test]
+    fn test_parse_lines_bad_id() {
+        assert_eq!(
+            parse_lines(vec!["3-5", "", "1", "5a"]),
+            Err(ParseError::new("5a", 1, "a number").at_line(4))
+        )
+    }
+
+    #[test]
+    fn test_parse_lines_extended() {
+        assert_eq!(
+            parse_lines(vec![
+                "# fresh",
+                "3-5",
+                "7",
+                "!4",
+                "100-",
+                "!150-",
+                "!120-130",
+                "",
+                "# available",
+                "1",
+                "5",
+            ]),
+            Ok(Ims {
+                fresh: vec![(3, 5), (7, 7), (100, u64::MAX)],
+                fresh_lines: vec![2, 3, 5],
+                excluded: vec![(4, 4), (150, u64::MAX), (120, 130)],
+                available: vec![1, 5]
+            })
+        )
+    }
+
+    #[test]
+    fn test_parse_lines_bad_range() {
+        assert_eq!(
+            parse_lines(vec!["3-5", "-5"]),
+            Err(ParseError::new("-5", 1, "a number").at_line(2))
+        );
+        assert_eq!(
+            parse_lines(vec!["3-5", "!3-x"]),
+            Err(ParseError::new("!3-x", 4, "a number").at_line(2))
+        );
+        assert_eq!(
+            parse_lines(vec!["3-5-7"]),
+            Err(ParseError::new("3-5-7", 4, "2 numbers separated by '-'"))
+        );
+        assert_eq!(
+            parse_lines(vec!["3-5", "", "!4"]),
+            Err(ParseError::new("!4", 1, "a number").at_line(3))
+        );
+    }
+
+    #[test]
+    fn test_fresh_ids() {
+        assert_eq!(
+            fresh_ids(
+                vec![(3, 5), (10, 20), (100, u64::MAX)],
+                &[(4, 4), (15, 200)]
+            )
+            .ranges(),
+            &[(3, 3), (5, 5), (10, 14), (201, u64::MAX)]
+        );
+    }
+
+    #[test]
+    fn test_condense_ranges() {
+        assert_eq!(
+            condense_ranges(vec![(3, 5), (10, 14), (16, 20), (12, 18)]).ranges(),
+            &[(3, 5), (10, 20)]
+        )
+    }
+
+    #[test]
+    fn test_condense_ranges_neighbors() {
+        assert_eq!(condense_ranges(vec![(3, 5), (6, 10)]).ranges(), &[(3, 10)])
+    }
+
+    #[test]
+    fn test_condense_ranges_nested() {
+        assert_eq!(condense_ranges(vec![(3, 10), (4, 7)]).ranges(), &[(3, 10)])
+    }
+
+    #[test]
+    fn test_condense_ranges_from_zero() {
+        assert_eq!(
+            condense_ranges(vec![(2, 4), (0, 0), (6, 8)]).ranges(),
+            &[(0, 0), (2, 4), (6, 8)]
+        )
+    }
+
+    #[test]
+    fn test_condense_ranges_full_domain() {
+        let condensed =
+            condense_ranges(vec![(0, 10), (u64::MAX - 5, u64::MAX), (11, u64::MAX - 6)]);
+        assert_eq!(condensed.ranges(), &[(0, u64::MAX)]);
+        assert_eq!(condensed.len(), 1 << 64);
+    }
+
+    #[test]
+    fn test_find_fresh_full_domain() {
+        let ims = Ims {
+            fresh: vec![(0, u64::MAX)],
+            available: vec![0, 1, u64::MAX],
+            ..Ims::default()
+        };
+        assert_eq!(Day05::part1(ims.clone()), 3);
+        assert_eq!(Day05::part2(ims), Some(1 << 64));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_find_fresh_matches_brute_force(
+            ranges in edge_ranges(),
+            available in edge_values(),
+        ) {
+            let fresh: BTreeSet<u64> = ranges.iter().flat_map(|(start, end)| *start..=*end).collect();
+            let ims = Ims { fresh: ranges, available: available.clone(), ..Ims::default() };
+            prop_assert_eq!(
+                Day05::part1(ims.clone()),
+                available.iter().filter(|item| fresh.contains(item)).count() as u128
+            );
+            prop_assert_eq!(Day05::part2(ims), Some(fresh.len() as u128));
+        }
+    }
+
+    #[test]
+    fn test_extended_syntax() {
+        let ims = Day05::parse("# stock\n3-5\n10-\n!12-14\n\n4\n11\n13\n99\n").unwrap();
+        assert_eq!(Day05::part1(ims.clone()), 3);
+        assert_eq!(Day05::part2(ims), Some(3 + (u64::MAX - 9) as u128 - 3));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day05::part1(Day05::parse(Day05::EXAMPLE).unwrap()), 3);
+        assert_eq!(
+            Day05::part2(Day05::parse(Day05::EXAMPLE_PART2).unwrap()),
+            Some(14)
+        );
+    }
+}
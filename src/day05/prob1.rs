@@ -0,0 +1,132 @@
+use super::{Ims, fresh_ids};
+use crate::interval_set::IntervalSet;
+
+/// How to check which available IDs are in the condensed fresh ranges
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Lookup {
+    /// Binary search the ranges for each ID
+    BinarySearch,
+    /// Sort the IDs and sweep through them and the ranges together
+    Sweep,
+}
+
+impl Lookup {
+    /// Choose the cheaper lookup for the number of available IDs and condensed ranges.  Binary
+    /// searching takes about `a log r` steps but jumps around the ranges, which costs far more
+    /// per step once the ranges no longer fit in cache, while sweeping takes about `a log a + r`
+    /// steps in order.  The costs were fitted to the `day05_lookups` benchmarks.
+    pub fn choose(available: usize, ranges: usize) -> Lookup {
+        let log2 = |n: usize| n.max(2).ilog2() as usize;
+        let search_step = if ranges <= CACHED_RANGES {
+            1
+        } else {
+            UNCACHED_SEARCH_STEP
+        };
+        let sweep = available * log2(available) + ranges;
+        let binary_search = available * log2(ranges) * search_step;
+        if sweep < binary_search {
+            Lookup::Sweep
+        } else {
+            Lookup::BinarySearch
+        }
+    }
+}
+
+/// The most ranges that comfortably fit in cache, at 16 bytes each
+const CACHED_RANGES: usize = 16_384;
+
+/// The cost of a binary search step over ranges that don't fit in cache, relative to a step of
+/// the sweep
+const UNCACHED_SEARCH_STEP: usize = 8;
+
+pub fn find_fresh(ims: Ims) -> usize {
+    let fresh = fresh_ids(ims.fresh, &ims.excluded);
+    let lookup = Lookup::choose(ims.available.len(), fresh.ranges().len());
+    log::debug!("Checking fresh IDs with {:?}", lookup);
+    count_fresh(&fresh, ims.available, lookup)
+}
+
+/// Count the available IDs that are fresh using `lookup`
+pub fn count_fresh(fresh: &IntervalSet<u64>, available: Vec<u64>, lookup: Lookup) -> usize {
+    match lookup {
+        Lookup::BinarySearch => available
+            .into_iter()
+            .filter(|item| fresh.contains(*item))
+            .count(),
+        Lookup::Sweep => count_fresh_sweep(fresh, available),
+    }
+}
+
+fn count_fresh_sweep(fresh: &IntervalSet<u64>, mut available: Vec<u64>) -> usize {
+    available.sort_unstable();
+    let ranges = fresh.ranges();
+    let mut range_i: usize = 0;
+    let mut count: usize = 0;
+    for item in available {
+        // a range that ends before this ID ends before every later ID too.  Only ever moving
+        // forward means an ID equal to the last is checked against the same range again.
+        while range_i < ranges.len() && ranges[range_i].1 < item {
+            range_i += 1;
+        }
+        if range_i == ranges.len() {
+            break;
+        }
+        if ranges[range_i].0 <= item {
+            count += 1;
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day05::condense_ranges;
+    use crate::interval_set::strategy::{edge_ranges, edge_values};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_find_fresh() {
+        assert_eq!(
+            find_fresh(Ims {
+                fresh: vec![(3, 5), (10, 14), (16, 20), (12, 18)],
+                available: vec![1, 5, 8, 11, 17, 32],
+                ..Ims::default()
+            }),
+            3
+        );
+    }
+
+    #[test]
+    fn test_count_fresh_sweep() {
+        let fresh = condense_ranges(vec![(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(count_fresh_sweep(&fresh, vec![32, 17, 11, 8, 5, 1]), 3);
+        // repeated IDs inside and past the last range
+        assert_eq!(count_fresh_sweep(&fresh, vec![20, 20, 21, 21, 3, 3]), 4);
+        assert_eq!(count_fresh_sweep(&fresh, vec![]), 0);
+        assert_eq!(count_fresh_sweep(&IntervalSet::new(), vec![1, 2]), 0);
+    }
+
+    #[test]
+    fn test_choose_lookup() {
+        assert_eq!(Lookup::choose(0, 100), Lookup::BinarySearch);
+        assert_eq!(Lookup::choose(10_000, 100), Lookup::BinarySearch);
+        assert_eq!(Lookup::choose(100, 1_000_000), Lookup::BinarySearch);
+        assert_eq!(Lookup::choose(10_000, 1_000_000), Lookup::Sweep);
+        assert_eq!(Lookup::choose(1_000_000, 1_000_000), Lookup::Sweep);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_sweep_matches_binary_search(
+            ranges in edge_ranges(),
+            available in edge_values(),
+        ) {
+            let fresh = condense_ranges(ranges);
+            prop_assert_eq!(
+                count_fresh(&fresh, available.clone(), Lookup::Sweep),
+                count_fresh(&fresh, available, Lookup::BinarySearch)
+            );
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use super::{Ims, fresh_ids};
+
+pub fn find_fresh(ims: Ims) -> u128 {
+    fresh_ids(ims.fresh, &ims.excluded).len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_fresh() {
+        assert_eq!(
+            find_fresh(Ims {
+                fresh: vec![(3, 5), (10, 14), (16, 20), (12, 18)],
+                available: vec![1, 5, 8, 11, 17, 32],
+                ..Ims::default()
+            }),
+            14
+        );
+    }
+}
@@ -0,0 +1,152 @@
+/// An index over the original fresh ranges, before they are condensed, for finding which ranges
+/// make an ID fresh.  A range is identified by its index in `Ims.fresh`, and its line number in
+/// the input is at the same index of `Ims.fresh_lines`.  Excluded ranges aren't indexed, so an
+/// ID can be covered by fresh ranges and still be excluded.
+///
+/// The ranges are kept sorted by start as an implicit balanced tree, where the range in the
+/// middle of a slice is the root of that slice and each root also records the furthest end of
+/// any range under it.  This lets a search skip every subtree that ends before the ID, so finding
+/// the `k` ranges covering an ID takes `O(k log n)`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Provenance {
+    /// Every non-empty range along with its index, sorted by start
+    by_start: Vec<(u64, u64, usize)>,
+    /// The furthest end of any range in the subtree rooted at each position of `by_start`
+    max_end: Vec<u64>,
+    /// The starts and ends of every non-empty range, each sorted on their own
+    starts: Vec<u64>,
+    ends: Vec<u64>,
+}
+
+impl Provenance {
+    pub fn new(ranges: &[(u64, u64)]) -> Provenance {
+        let mut by_start: Vec<(u64, u64, usize)> = ranges
+            .iter()
+            .enumerate()
+            .filter(|(_, (start, end))| start <= end)
+            .map(|(i, (start, end))| (*start, *end, i))
+            .collect();
+        by_start.sort_unstable();
+
+        let mut max_end = vec![0; by_start.len()];
+        build_max_end(&by_start, &mut max_end, 0, by_start.len());
+
+        let mut starts: Vec<u64> = by_start.iter().map(|range| range.0).collect();
+        let mut ends: Vec<u64> = by_start.iter().map(|range| range.1).collect();
+        starts.sort_unstable();
+        ends.sort_unstable();
+
+        Provenance {
+            by_start,
+            max_end,
+            starts,
+            ends,
+        }
+    }
+
+    /// The index of every range covering `id`, in ascending order
+    pub fn covering(&self, id: u64) -> Vec<usize> {
+        let mut covering: Vec<usize> = Vec::new();
+        self.search(id, 0, self.by_start.len(), &mut covering);
+        covering.sort_unstable();
+        covering
+    }
+
+    fn search(&self, id: u64, lo: usize, hi: usize, covering: &mut Vec<usize>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if self.max_end[mid] < id {
+            return;
+        }
+        self.search(id, lo, mid, covering);
+        let (start, end, i) = self.by_start[mid];
+        // everything to the right starts at least as late, so can only cover the ID if this does
+        if start <= id {
+            if id <= end {
+                covering.push(i);
+            }
+            self.search(id, mid + 1, hi, covering);
+        }
+    }
+
+    /// The number of ranges covering `id`: every range that starts at or before it, less those
+    /// that also end before it
+    pub fn depth(&self, id: u64) -> usize {
+        let started = self.starts.partition_point(|start| *start <= id);
+        let ended = self.ends.partition_point(|end| *end < id);
+        started - ended
+    }
+}
+
+/// Record the furthest end under each root of the implicit tree over `by_start[lo..hi]`,
+/// returning the furthest end of the whole slice
+fn build_max_end(by_start: &[(u64, u64, usize)], max_end: &mut [u64], lo: usize, hi: usize) -> u64 {
+    if lo >= hi {
+        return 0;
+    }
+    let mid = lo + (hi - lo) / 2;
+    let left = build_max_end(by_start, max_end, lo, mid);
+    let right = build_max_end(by_start, max_end, mid + 1, hi);
+    max_end[mid] = by_start[mid].1.max(left).max(right);
+    max_end[mid]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::interval_set::strategy::{edge_ranges, edge_values};
+    use proptest::prelude::*;
+
+    fn example() -> Provenance {
+        Provenance::new(&[(3, 5), (10, 14), (16, 20), (12, 18)])
+    }
+
+    #[test]
+    fn test_covering() {
+        let provenance = example();
+        assert_eq!(provenance.covering(17), vec![2, 3]);
+        assert_eq!(provenance.covering(5), vec![0]);
+        assert_eq!(provenance.covering(12), vec![1, 3]);
+        assert_eq!(provenance.covering(8), Vec::<usize>::new());
+        assert_eq!(provenance.covering(32), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_depth() {
+        let provenance = example();
+        assert_eq!(provenance.depth(17), 2);
+        assert_eq!(provenance.depth(3), 1);
+        assert_eq!(provenance.depth(9), 0);
+        assert_eq!(provenance.depth(0), 0);
+    }
+
+    #[test]
+    fn test_edges() {
+        let provenance = Provenance::new(&[(0, u64::MAX), (u64::MAX, u64::MAX), (5, 3), (0, 0)]);
+        assert_eq!(provenance.covering(0), vec![0, 3]);
+        assert_eq!(provenance.covering(u64::MAX), vec![0, 1]);
+        assert_eq!(provenance.covering(4), vec![0]);
+        assert_eq!(provenance.depth(u64::MAX), 2);
+        assert_eq!(provenance.depth(4), 1);
+        assert_eq!(Provenance::new(&[]).covering(1), Vec::<usize>::new());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_covering_matches_brute_force(
+            ranges in edge_ranges(),
+            ids in edge_values(),
+        ) {
+            let provenance = Provenance::new(&ranges);
+            for id in ids {
+                let expected: Vec<usize> = (0..ranges.len())
+                    .filter(|i| ranges[*i].0 <= id && id <= ranges[*i].1)
+                    .collect();
+                prop_assert_eq!(provenance.depth(id), expected.len());
+                prop_assert_eq!(provenance.covering(id), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use super::fresh_ids;
+use crate::interval_set::Endpoint;
+use serde::Serialize;
+use std::fmt;
+
+/// A summary of how the fresh ranges cover the IDs between the lowest and highest fresh ID.
+/// Ranges whose start is after their end hold no IDs, so are left out of every figure.  The
+/// condensed ranges, span, coverage and gaps are of the fresh IDs left after the exclusions, while
+/// the other figures are of the fresh ranges as given.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct CoverageReport {
+    /// The number of input ranges, and of ranges left after merging those that overlap or touch
+    /// and removing the exclusions
+    pub ranges: usize,
+    pub condensed: usize,
+    /// The input ranges that lie entirely within another input range, so add no fresh IDs.  Of
+    /// several identical ranges, all but the first are counted.
+    pub redundant: usize,
+    /// The lowest and highest fresh IDs, if there are any
+    pub span: Option<(u64, u64)>,
+    /// The number of fresh IDs, and that as a percentage of the IDs in the span
+    pub covered: u128,
+    pub coverage_percent: f64,
+    /// The runs of IDs within the span that aren't fresh, in order
+    pub gaps: Vec<(u64, u64)>,
+    /// The longest gap, or the first of the longest if several are the same length
+    pub largest_gap: Option<(u64, u64)>,
+    /// The number of input ranges of each width, bucketed by the number of decimal digits in the
+    /// width, leaving out empty buckets
+    pub widths: Vec<WidthBucket>,
+}
+
+/// The number of ranges whose widths are from `min` to `max` IDs inclusive
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct WidthBucket {
+    pub min: u128,
+    pub max: u128,
+    pub count: usize,
+}
+
+/// The number of IDs in a range
+fn width((start, end): (u64, u64)) -> u128 {
+    u64::count(start, end)
+}
+
+impl CoverageReport {
+    pub fn new(fresh: &[(u64, u64)], excluded: &[(u64, u64)]) -> CoverageReport {
+        let ranges: Vec<(u64, u64)> = fresh
+            .iter()
+            .copied()
+            .filter(|(start, end)| start <= end)
+            .collect();
+        let condensed = fresh_ids(ranges.clone(), excluded);
+        let span = condensed
+            .ranges()
+            .first()
+            .zip(condensed.ranges().last())
+            .map(|(first, last)| (first.0, last.1));
+        let covered = condensed.len();
+        let coverage_percent = span.map_or(0.0, |span| covered as f64 / width(span) as f64 * 100.0);
+        let gaps: Vec<(u64, u64)> = condensed.gaps().collect();
+        let largest_gap = gaps.iter().copied().reduce(|largest, gap| {
+            if width(gap) > width(largest) {
+                gap
+            } else {
+                largest
+            }
+        });
+
+        CoverageReport {
+            ranges: ranges.len(),
+            condensed: condensed.ranges().len(),
+            redundant: count_redundant(&ranges),
+            span,
+            covered,
+            coverage_percent,
+            gaps,
+            largest_gap,
+            widths: width_histogram(&ranges),
+        }
+    }
+}
+
+/// Count the ranges that lie entirely within another.  Sorting by start, and by end descending
+/// between ranges with the same start, puts every range after all of the ranges that could
+/// contain it, so a range is nested if an earlier range reaches at least as far.
+fn count_redundant(ranges: &[(u64, u64)]) -> usize {
+    let mut sorted = ranges.to_vec();
+    sorted.sort_unstable_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+    let mut furthest: Option<u64> = None;
+    let mut redundant: usize = 0;
+    for (_, end) in sorted {
+        match furthest {
+            Some(furthest) if end <= furthest => redundant += 1,
+            _ => furthest = Some(end),
+        }
+    }
+    redundant
+}
+
+/// Bucket the widths of the ranges by their number of decimal digits
+fn width_histogram(ranges: &[(u64, u64)]) -> Vec<WidthBucket> {
+    // a width is at most 2^64, which has 20 digits
+    let mut counts = [0usize; 20];
+    for range in ranges {
+        counts[width(*range).ilog10() as usize] += 1;
+    }
+    counts
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count > 0)
+        .map(|(digits, count)| WidthBucket {
+            min: 10u128.pow(digits as u32),
+            max: 10u128.pow(digits as u32 + 1) - 1,
+            count: *count,
+        })
+        .collect()
+}
+
+/// Draws the report as a summary followed by tables of the gaps and range widths
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let range = |(start, end): (u64, u64)| format!("{}-{}", start, end);
+        writeln!(
+            f,
+            "{:<12}  {} ({} condensed, {} redundant)",
+            "Ranges", self.ranges, self.condensed, self.redundant
+        )?;
+        match self.span {
+            Some(span) => writeln!(f, "{:<12}  {} ({} IDs)", "Span", range(span), width(span))?,
+            None => writeln!(f, "{:<12}  none", "Span")?,
+        }
+        writeln!(
+            f,
+            "{:<12}  {} IDs ({:.2}%)",
+            "Covered", self.covered, self.coverage_percent
+        )?;
+        writeln!(f, "{:<12}  {}", "Gaps", self.gaps.len())?;
+        match self.largest_gap {
+            Some(gap) => writeln!(
+                f,
+                "{:<12}  {} ({} IDs)",
+                "Largest gap",
+                range(gap),
+                width(gap)
+            )?,
+            None => writeln!(f, "{:<12}  none", "Largest gap")?,
+        }
+
+        if !self.gaps.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{:<41}  {:>20}", "Gap", "IDs")?;
+            for gap in self.gaps.iter() {
+                writeln!(f, "{:<41}  {:>20}", range(*gap), width(*gap))?;
+            }
+        }
+
+        if !self.widths.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{:<41}  {:>20}", "Width", "Ranges")?;
+            for bucket in self.widths.iter() {
+                writeln!(
+                    f,
+                    "{:<41}  {:>20}",
+                    format!("{}-{}", bucket.min, bucket.max),
+                    bucket.count
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> CoverageReport {
+        CoverageReport::new(&[(3, 5), (10, 14), (16, 20), (12, 18)], &[])
+    }
+
+    #[test]
+    fn test_coverage_report() {
+        let report = example();
+        assert_eq!(report.ranges, 4);
+        assert_eq!(report.condensed, 2);
+        assert_eq!(report.redundant, 0);
+        assert_eq!(report.span, Some((3, 20)));
+        assert_eq!(report.covered, 14);
+        assert!((report.coverage_percent - 14.0 / 18.0 * 100.0).abs() < 1e-9);
+        assert_eq!(report.gaps, vec![(6, 9)]);
+        assert_eq!(report.largest_gap, Some((6, 9)));
+        assert_eq!(
+            report.widths,
+            vec![WidthBucket {
+                min: 1,
+                max: 9,
+                count: 4
+            }]
+        );
+    }
+
+    #[test]
+    fn test_coverage_report_empty() {
+        let report = CoverageReport::new(&[(5, 3)], &[]);
+        assert_eq!(report.ranges, 0);
+        assert_eq!(report.span, None);
+        assert_eq!(report.coverage_percent, 0.0);
+        assert_eq!(report.largest_gap, None);
+        assert_eq!(report.widths, vec![]);
+    }
+
+    #[test]
+    fn test_largest_gap() {
+        let report = CoverageReport::new(
+            &[(0, 0), (5, 5), (10, 10), (12, 12), (u64::MAX, u64::MAX)],
+            &[],
+        );
+        assert_eq!(report.gaps.len(), 4);
+        assert_eq!(report.largest_gap, Some((13, u64::MAX - 1)));
+        assert_eq!(report.span, Some((0, u64::MAX)));
+        let report = CoverageReport::new(&[(0, 0), (5, 5), (10, 10)], &[]);
+        assert_eq!(report.largest_gap, Some((1, 4)));
+    }
+
+    #[test]
+    fn test_coverage_report_excluded() {
+        let report = CoverageReport::new(&[(3, 5), (10, 20)], &[(13, 14), (18, 30)]);
+        assert_eq!(report.ranges, 2);
+        assert_eq!(report.condensed, 3);
+        assert_eq!(report.span, Some((3, 17)));
+        assert_eq!(report.covered, 9);
+        assert_eq!(report.gaps, vec![(6, 9), (13, 14)]);
+    }
+
+    #[test]
+    fn test_count_redundant() {
+        assert_eq!(
+            count_redundant(&[(1, 10), (2, 5), (1, 10), (8, 12), (12, 12)]),
+            3
+        );
+        assert_eq!(count_redundant(&[(1, 5), (5, 10)]), 0);
+        assert_eq!(count_redundant(&[(3, 7), (1, 7)]), 1);
+    }
+
+    #[test]
+    fn test_width_histogram() {
+        assert_eq!(
+            width_histogram(&[(1, 9), (0, 9), (5, 104), (0, u64::MAX)]),
+            vec![
+                WidthBucket {
+                    min: 1,
+                    max: 9,
+                    count: 1
+                },
+                WidthBucket {
+                    min: 10,
+                    max: 99,
+                    count: 1
+                },
+                WidthBucket {
+                    min: 100,
+                    max: 999,
+                    count: 1
+                },
+                WidthBucket {
+                    min: 10u128.pow(19),
+                    max: 10u128.pow(20) - 1,
+                    count: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            example().to_string(),
+            format!(
+                "Ranges        4 (2 condensed, 0 redundant)\n\
+                 Span          3-20 (18 IDs)\n\
+                 Covered       14 IDs (77.78%)\n\
+                 Gaps          1\n\
+                 Largest gap   6-9 (4 IDs)\n\
+                 \n\
+                 {:<41}  {:>20}\n\
+                 {:<41}  {:>20}\n\
+                 \n\
+                 {:<41}  {:>20}\n\
+                 {:<41}  {:>20}\n",
+                "Gap", "IDs", "6-9", 4, "Width", "Ranges", "1-9", 4
+            )
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let report = CoverageReport::new(&[(3, 5), (4, 4)], &[]);
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            "{\"ranges\":2,\"condensed\":1,\"redundant\":1,\"span\":[3,5],\"covered\":3,\
+             \"coverage_percent\":100.0,\"gaps\":[],\"largest_gap\":null,\
+             \"widths\":[{\"min\":1,\"max\":9,\"count\":2}]}"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::Solution;
+use crate::error::ParseError;
+
+pub mod prob1;
+pub mod prob2;
+
+pub struct Day06;
+
+/// The homework worksheet, read both as rows of whitespace-separated numbers and as right-to-left
+/// columns of digits
+#[derive(Clone, PartialEq, Debug)]
+pub struct Worksheet {
+    pub by_row: (Vec<Vec<u64>>, Vec<Op>),
+    pub by_column: (Vec<Vec<u64>>, Vec<Op>),
+}
+
+impl Solution for Day06 {
+    type Input = Worksheet;
+    type Output = u64;
+
+    const EXAMPLE: &'static str = concat!(
+        "123 328  51 64 \n",
+        " 45 64  387 23 \n",
+        "  6 98  215 314\n",
+        "*   +   *   +  \n",
+    );
+
+    fn parse(input: &str) -> Result<Worksheet, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        Ok(Worksheet {
+            by_row: prob1::parse_lines(lines.clone())?,
+            by_column: prob2::parse_lines(lines)?,
+        })
+    }
+
+    fn part1(worksheet: Worksheet) -> u64 {
+        let (num_lines, op_line) = worksheet.by_row;
+        prob1::do_math(num_lines, op_line)
+    }
+
+    fn part2(worksheet: Worksheet) -> Option<u64> {
+        let (num_lines, op_line) = worksheet.by_column;
+        Some(prob2::do_math(num_lines, op_line))
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Op {
+    Add,
+    Mul,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day06::part1(Day06::parse(Day06::EXAMPLE).unwrap()), 4277556);
+        assert_eq!(
+            Day06::part2(Day06::parse(Day06::EXAMPLE_PART2).unwrap()),
+            Some(3263827)
+        );
+    }
+}
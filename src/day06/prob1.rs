@@ -0,0 +1,116 @@
+use super::Op;
+use crate::error::{ParseError, parse_number};
+use regex::Regex;
+
+pub fn parse_lines(lines: Vec<&str>) -> Result<(Vec<Vec<u64>>, Vec<Op>), ParseError> {
+    let separator = Regex::new(r"\s+").expect("Invalid regex");
+    let op_line_no = lines.len();
+    let Some((op_raw, num_raws)) = lines.split_last() else {
+        return Err(ParseError::new("", 1, "a line of operations"));
+    };
+
+    let op_line: Vec<Op> = separator
+        .split(op_raw.trim())
+        .map(|raw_op| match raw_op {
+            "*" => Ok(Op::Mul),
+            "+" => Ok(Op::Add),
+            _ => Err(
+                ParseError::at_token(op_raw, raw_op, "an operation ('*' or '+')")
+                    .at_line(op_line_no),
+            ),
+        })
+        .collect::<Result<_, _>>()?;
+    let num_lines: Vec<Vec<u64>> = num_raws
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            separator
+                .split(line.trim())
+                .map(|raw_num| parse_number(line, raw_num).map_err(|err| err.at_line(i + 1)))
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    for (i, num_line) in num_lines.iter().enumerate() {
+        if op_line.len() != num_line.len() {
+            return Err(ParseError::new(
+                num_raws[i],
+                1,
+                format!("{} numbers, one per operation", op_line.len()),
+            )
+            .at_line(i + 1));
+        }
+    }
+
+    Ok((num_lines, op_line))
+}
+
+pub fn do_math(num_lines: Vec<Vec<u64>>, op_line: Vec<Op>) -> u64 {
+    op_line
+        .into_iter()
+        .enumerate()
+        .map(|(i, op)| {
+            let input = num_lines.iter().map(|num_line| num_line[i]);
+            match op {
+                Op::Add => input.sum::<u64>(),
+                Op::Mul => input.product(),
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines(vec![
+                "123 328  51 64 ",
+                " 45 64  387 23 ",
+                "  6 98  215 314",
+                "*   +   *   +  ",
+            ]),
+            Ok((
+                vec![
+                    vec![123, 328, 51, 64],
+                    vec![45, 64, 387, 23],
+                    vec![6, 98, 215, 314]
+                ],
+                vec![Op::Mul, Op::Add, Op::Mul, Op::Add]
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_lines_bad_op() {
+        assert_eq!(
+            parse_lines(vec!["123 328", " 45 64 ", "*   -  "]),
+            Err(ParseError::new("*   -  ", 5, "an operation ('*' or '+')").at_line(3))
+        )
+    }
+
+    #[test]
+    fn test_parse_lines_short_line() {
+        assert_eq!(
+            parse_lines(vec!["123 328", " 45    ", "*   +  "]),
+            Err(ParseError::new(" 45    ", 1, "2 numbers, one per operation").at_line(2))
+        )
+    }
+
+    #[test]
+    fn test_do_math() {
+        assert_eq!(
+            do_math(
+                vec![
+                    vec![123, 328, 51, 64],
+                    vec![45, 64, 387, 23],
+                    vec![6, 98, 215, 314]
+                ],
+                vec![Op::Mul, Op::Add, Op::Mul, Op::Add]
+            ),
+            4277556
+        )
+    }
+}
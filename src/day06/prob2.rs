@@ -0,0 +1,137 @@
+use super::Op;
+use crate::error::ParseError;
+use crate::grid::Grid;
+
+pub fn parse_lines(lines: Vec<&str>) -> Result<(Vec<Vec<u64>>, Vec<Op>), ParseError> {
+    let op_line_no = lines.len();
+    let Some((op_raw, num_raws)) = lines.split_last() else {
+        return Err(ParseError::new("", 1, "a line of operations"));
+    };
+
+    let op_line: Vec<(usize, Op)> = op_raw
+        .chars()
+        .enumerate()
+        .filter(|(_, char)| *char != ' ')
+        .map(|(i, char)| match char {
+            '*' => Ok((i, Op::Mul)),
+            '+' => Ok((i, Op::Add)),
+            _ => {
+                Err(ParseError::new(op_raw, i + 1, "an operation ('*' or '+')").at_line(op_line_no))
+            }
+        })
+        .collect::<Result<_, _>>()?;
+
+    // editors may strip trailing spaces, so treat a short row as blank in the missing columns
+    let digits = Grid::from_ragged_rows(
+        num_raws.iter().map(|line| line.chars().collect()).collect(),
+        ' ',
+    );
+    let mut right_limit = digits.width();
+    let mut nums_for_op: Vec<Vec<u64>> = vec![vec![]; op_line.len()];
+    for (op_i, (left_limit, _)) in op_line.iter().enumerate().rev() {
+        let mut nums: Vec<u64> = Vec::new();
+        for col_i in (*left_limit..right_limit).rev() {
+            let raw_num: String = digits.column(col_i).collect();
+            let num: u64 = raw_num.trim().parse().map_err(|_| {
+                let row = bad_row(&raw_num);
+                ParseError::new(
+                    num_raws[row],
+                    col_i + 1,
+                    "a column of digits forming a number",
+                )
+                .at_line(row + 1)
+            })?;
+            nums.push(num);
+        }
+        nums_for_op[op_i] = nums;
+
+        if *left_limit > 0 {
+            right_limit = right_limit.min(*left_limit - 1);
+        }
+    }
+
+    Ok((nums_for_op, op_line.into_iter().map(|(_, op)| op).collect()))
+}
+
+/// The row of a column that doesn't form a number: the first that isn't a digit or a space, or
+/// failing that the first space splitting the digits, or the top row if the column is blank
+fn bad_row(column: &str) -> usize {
+    let chars: Vec<char> = column.chars().collect();
+    chars
+        .iter()
+        .position(|char| *char != ' ' && !char.is_ascii_digit())
+        .or_else(|| {
+            let first_digit = chars.iter().position(|char| char.is_ascii_digit())?;
+            chars[first_digit..]
+                .iter()
+                .position(|char| *char == ' ')
+                .map(|i| first_digit + i)
+        })
+        .unwrap_or(0)
+}
+
+pub fn do_math(num_lines: Vec<Vec<u64>>, op_line: Vec<Op>) -> u64 {
+    op_line
+        .into_iter()
+        .enumerate()
+        .map(|(i, op)| match op {
+            Op::Add => num_lines[i].iter().sum::<u64>(),
+            Op::Mul => num_lines[i].iter().product(),
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines(vec![
+                "123 328  51 64 ",
+                " 45 64  387 23 ",
+                "  6 98  215 314",
+                "*   +   *   +  ",
+            ]),
+            Ok((
+                vec![
+                    vec![356, 24, 1],
+                    vec![8, 248, 369],
+                    vec![175, 581, 32],
+                    vec![4, 431, 623],
+                ],
+                vec![Op::Mul, Op::Add, Op::Mul, Op::Add]
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_lines_bad_digit() {
+        assert_eq!(
+            parse_lines(vec!["12 3", "4x 5", "*  +"]),
+            Err(ParseError::new("4x 5", 2, "a column of digits forming a number").at_line(2))
+        );
+        // a space between the digits of a column
+        assert_eq!(
+            parse_lines(vec!["1", " ", "2", "*"]),
+            Err(ParseError::new(" ", 1, "a column of digits forming a number").at_line(2))
+        );
+    }
+
+    #[test]
+    fn test_do_math() {
+        assert_eq!(
+            do_math(
+                vec![
+                    vec![356, 24, 1],
+                    vec![8, 248, 369],
+                    vec![175, 581, 32],
+                    vec![4, 431, 623],
+                ],
+                vec![Op::Mul, Op::Add, Op::Mul, Op::Add]
+            ),
+            3263827
+        )
+    }
+}
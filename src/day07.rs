@@ -0,0 +1,196 @@
+use crate::Solution;
+use crate::error::ParseError;
+use crate::grid::Grid;
+
+pub mod prob1;
+pub mod prob2;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = (usize, Grid<bool>);
+    type Output = usize;
+
+    const EXAMPLE: &'static str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    fn parse(input: &str) -> Result<(usize, Grid<bool>), ParseError> {
+        parse_lines(input.lines().collect())
+    }
+
+    fn part1((start_i, splitters): (usize, Grid<bool>)) -> usize {
+        prob1::propagate_beam(start_i, splitters)
+    }
+
+    fn part2((start_i, splitters): (usize, Grid<bool>)) -> Option<usize> {
+        Some(prob2::propagate_beam(start_i, splitters))
+    }
+}
+
+pub fn parse_lines(lines: Vec<&str>) -> Result<(usize, Grid<bool>), ParseError> {
+    let first_line = lines.first().copied().unwrap_or("");
+    let start_i: usize = first_line
+        .chars()
+        .enumerate()
+        .filter_map(|(i, char)| if char == 'S' { Some(i) } else { None })
+        .next()
+        .ok_or_else(|| ParseError::new(first_line, 1, "a starting position ('S')"))?;
+
+    let splitters = Grid::parse(
+        &lines[1..].join("\n"),
+        |char| match char {
+            '.' => Some(false),
+            '^' => Some(true),
+            _ => None,
+        },
+        "'.' or '^'",
+    )
+    // the splitters start on the second line
+    .map_err(|err| {
+        let line = err.line + 1;
+        err.at_line(line)
+    })?;
+
+    if splitters.height() > 0 && start_i >= splitters.width() {
+        return Err(ParseError::new(
+            first_line,
+            start_i + 1,
+            format!(
+                "a starting position within the {} columns below",
+                splitters.width()
+            ),
+        ));
+    }
+    // a beam split at either edge would leave the map
+    for (line_i, line) in lines.iter().enumerate().skip(1) {
+        let len = line.chars().count();
+        if let Some(i) = line
+            .chars()
+            .enumerate()
+            .position(|(i, char)| char == '^' && (i == 0 || i + 1 == len))
+        {
+            return Err(
+                ParseError::new(line, i + 1, "a splitter away from the edges").at_line(line_i + 1),
+            );
+        }
+    }
+
+    Ok((start_i, splitters))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let t = true;
+        let f = false;
+
+        assert_eq!(
+            parse_lines(vec![
+                ".......S.......",
+                "...............",
+                ".......^.......",
+                "...............",
+                "......^.^......",
+                "...............",
+                ".....^.^.^.....",
+                "...............",
+                "....^.^...^....",
+                "...............",
+                "...^.^...^.^...",
+                "...............",
+                "..^...^.....^..",
+                "...............",
+                ".^.^.^.^.^...^.",
+                "...............",
+            ]),
+            Ok((
+                7,
+                Grid::from_rows(vec![
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, t, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, t, f, t, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, t, f, t, f, t, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, t, f, t, f, f, f, t, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, t, f, t, f, f, f, t, f, t, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, t, f, f, f, t, f, f, f, f, f, t, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, t, f, t, f, t, f, t, f, t, f, f, f, t, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                ])
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_lines_missing_start() {
+        assert_eq!(
+            parse_lines(vec!["....", "..^."]),
+            Err(ParseError::new("....", 1, "a starting position ('S')"))
+        )
+    }
+
+    #[test]
+    fn test_parse_lines_bad_char() {
+        assert_eq!(
+            parse_lines(vec![".S..", "....", ".^#."]),
+            Err(ParseError::new(".^#.", 3, "'.' or '^'").at_line(3))
+        )
+    }
+
+    #[test]
+    fn test_parse_lines_start_outside() {
+        assert_eq!(
+            parse_lines(vec!["..S..", ".."]),
+            Err(ParseError::new(
+                "..S..",
+                3,
+                "a starting position within the 2 columns below"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_lines_splitter_at_edge() {
+        assert_eq!(
+            parse_lines(vec![".S..", "....", "...^"]),
+            Err(ParseError::new("...^", 4, "a splitter away from the edges").at_line(3))
+        );
+        assert_eq!(
+            parse_lines(vec![".S..", "^..."]),
+            Err(ParseError::new("^...", 1, "a splitter away from the edges").at_line(2))
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day07::part1(Day07::parse(Day07::EXAMPLE).unwrap()), 21);
+        assert_eq!(
+            Day07::part2(Day07::parse(Day07::EXAMPLE_PART2).unwrap()),
+            Some(40)
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use crate::grid::Grid;
+use std::collections::HashSet;
+
+pub fn propagate_beam(start_i: usize, splitters: Grid<bool>) -> usize {
+    let mut prev: HashSet<usize> = HashSet::new();
+    prev.insert(start_i);
+    let mut cur: HashSet<usize> = HashSet::new();
+    let mut count: usize = 0;
+
+    for row in splitters.rows() {
+        for beam in prev.iter() {
+            if row[*beam] {
+                cur.insert(beam - 1);
+                cur.insert(beam + 1);
+                count += 1;
+            } else {
+                cur.insert(*beam);
+            }
+        }
+        std::mem::swap(&mut prev, &mut cur);
+        cur.clear();
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_propagate_beam() {
+        let t = true;
+        let f = false;
+
+        assert_eq!(
+            propagate_beam(
+                7,
+                Grid::from_rows(vec![
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, t, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, t, f, t, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, t, f, t, f, t, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, t, f, t, f, f, f, t, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, t, f, t, f, f, f, t, f, t, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, t, f, f, f, t, f, f, f, f, f, t, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, t, f, t, f, t, f, t, f, t, f, f, f, t, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                ])
+            ),
+            21
+        )
+    }
+}
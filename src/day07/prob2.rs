@@ -0,0 +1,73 @@
+use crate::grid::Grid;
+
+pub fn propagate_beam(start_i: usize, splitters: Grid<bool>) -> usize {
+    let mut prev: Vec<usize> = vec![0; splitters.width()];
+    prev[start_i] = 1;
+    let mut cur: Vec<usize> = vec![0; splitters.width()];
+
+    for row in splitters.rows() {
+        for (i, count) in prev.iter().enumerate() {
+            if *count > 0 {
+                if row[i] {
+                    cur[i - 1] += count;
+                    cur[i + 1] += count;
+                } else {
+                    cur[i] += *count;
+                }
+            }
+        }
+        // {
+        //     for (i, count) in cur.iter().enumerate() {
+        //         match *count {
+        //             0 if row[i] => print!("^"),
+        //             0 => print!("."),
+        //             count if count < 10 => print!("{}", count),
+        //             count if (10..16).contains(&count) => {
+        //                 print!("{}", b'a' + (count - 10) as u8)
+        //             }
+        //             count => panic!("Unable to handle counts > 16 ({})", count),
+        //         };
+        //     }
+        //     println!();
+        // }
+        std::mem::swap(&mut prev, &mut cur);
+        cur.fill(0);
+    }
+
+    prev.into_iter().sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_propagate_beam() {
+        let t = true;
+        let f = false;
+
+        assert_eq!(
+            propagate_beam(
+                7,
+                Grid::from_rows(vec![
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, t, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, t, f, t, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, f, t, f, t, f, t, f, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, f, t, f, t, f, f, f, t, f, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, f, t, f, t, f, f, f, t, f, t, f, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, f, t, f, f, f, t, f, f, f, f, f, t, f, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                    vec![f, t, f, t, f, t, f, t, f, t, f, f, f, t, f],
+                    vec![f, f, f, f, f, f, f, f, f, f, f, f, f, f, f],
+                ])
+            ),
+            40
+        )
+    }
+}
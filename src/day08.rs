@@ -0,0 +1,233 @@
+use crate::Solution;
+use crate::error::{ParseError, parse_non_empty_lines, parse_numbers};
+use std::cmp::PartialOrd;
+use std::rc::Rc;
+
+pub mod prob1;
+pub mod prob2;
+
+pub struct Day08;
+
+/// The number of closest pairs of boxes the first part connects in the puzzle, and in the example
+pub const CONNECTIONS: u32 = 1000;
+pub const EXAMPLE_CONNECTIONS: u32 = 10;
+
+/// The junction boxes, along with the number of closest pairs of them for the first part to
+/// connect
+#[derive(Clone, PartialEq, Debug)]
+pub struct Playground {
+    pub boxes: Vec<Pos>,
+    pub connections: u32,
+}
+
+impl Solution for Day08 {
+    type Input = Playground;
+    type Output = u64;
+
+    const EXAMPLE: &'static str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+    fn parse(input: &str) -> Result<Playground, ParseError> {
+        parse_playground(input, CONNECTIONS)
+    }
+
+    fn parse_example(input: &str) -> Result<Playground, ParseError> {
+        parse_playground(input, EXAMPLE_CONNECTIONS)
+    }
+
+    fn part1(playground: Playground) -> u64 {
+        prob1::build_circuits(playground.boxes, playground.connections)
+    }
+
+    fn part2(playground: Playground) -> Option<u64> {
+        Some(prob2::build_circuits(playground.boxes))
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Debug)]
+pub struct Pos {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+/// Parse a box per line, with `connections` of the closest pairs for the first part to connect
+pub fn parse_playground(input: &str, connections: u32) -> Result<Playground, ParseError> {
+    Ok(Playground {
+        boxes: parse_non_empty_lines(input, parse_line)?,
+        connections,
+    })
+}
+
+pub fn parse_line(line: &str) -> Result<Pos, ParseError> {
+    let [x, y, z] = parse_numbers(line, line, ",")?;
+    Ok(Pos { x, y, z })
+}
+
+pub fn distance(pos1: &Pos, pos2: &Pos) -> f32 {
+    (((pos1.x as i64 - pos2.x as i64).pow(2)
+        + (pos1.y as i64 - pos2.y as i64).pow(2)
+        + (pos1.z as i64 - pos2.z as i64).pow(2)) as f32)
+        .sqrt()
+}
+
+pub fn calc_distances(boxes: Vec<Pos>) -> Vec<(f32, Rc<Pos>, Rc<Pos>)> {
+    let rc_boxes: Vec<Rc<Pos>> = boxes.into_iter().map(Rc::new).collect();
+    let mut out_boxes: Vec<(f32, Rc<Pos>, Rc<Pos>)> = rc_boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, box1)| {
+            rc_boxes
+                .iter()
+                .skip(i + 1)
+                .map(|box2| {
+                    if box1 < box2 {
+                        (distance(box1, box2), box1.clone(), box2.clone())
+                    } else {
+                        (distance(box2, box1), box2.clone(), box1.clone())
+                    }
+                })
+                .collect::<Vec<(f32, Rc<Pos>, Rc<Pos>)>>()
+        })
+        .collect();
+    out_boxes.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    out_boxes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("162,817,812"),
+            Ok(Pos {
+                x: 162,
+                y: 817,
+                z: 812
+            })
+        )
+    }
+
+    #[test]
+    fn test_calc_distances() {
+        assert_eq!(
+            calc_distances(vec![
+                Pos {
+                    x: 162,
+                    y: 817,
+                    z: 812
+                },
+                Pos {
+                    x: 425,
+                    y: 690,
+                    z: 689
+                },
+                Pos {
+                    x: 431,
+                    y: 825,
+                    z: 988
+                },
+                Pos {
+                    x: 906,
+                    y: 360,
+                    z: 560
+                },
+                Pos {
+                    x: 805,
+                    y: 96,
+                    z: 715
+                },
+            ])
+            .into_iter()
+            .rev()
+            .take(4)
+            .rev()
+            .map(|(_, box1, box2)| (box1, box2))
+            .collect::<Vec<(Rc<Pos>, Rc<Pos>)>>(),
+            vec![
+                (
+                    Rc::new(Pos {
+                        x: 425,
+                        y: 690,
+                        z: 689
+                    }),
+                    Rc::new(Pos {
+                        x: 431,
+                        y: 825,
+                        z: 988
+                    })
+                ),
+                (
+                    Rc::new(Pos {
+                        x: 805,
+                        y: 96,
+                        z: 715
+                    }),
+                    Rc::new(Pos {
+                        x: 906,
+                        y: 360,
+                        z: 560
+                    })
+                ),
+                (
+                    Rc::new(Pos {
+                        x: 162,
+                        y: 817,
+                        z: 812
+                    }),
+                    Rc::new(Pos {
+                        x: 431,
+                        y: 825,
+                        z: 988
+                    })
+                ),
+                (
+                    Rc::new(Pos {
+                        x: 162,
+                        y: 817,
+                        z: 812
+                    }),
+                    Rc::new(Pos {
+                        x: 425,
+                        y: 690,
+                        z: 689
+                    })
+                )
+            ]
+        )
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(
+            Day08::part1(Day08::parse_example(Day08::EXAMPLE).unwrap()),
+            40
+        );
+        assert_eq!(
+            Day08::part2(Day08::parse_example(Day08::EXAMPLE_PART2).unwrap()),
+            Some(25272)
+        );
+    }
+}
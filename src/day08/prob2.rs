@@ -1,69 +1,8 @@
-use std::cmp::PartialOrd;
+use super::{Pos, calc_distances};
 use std::collections::HashMap;
 use std::rc::Rc;
 
-fn main() {
-    let boxes: Vec<Pos> = std::fs::read_to_string("src/bin/day08.txt")
-        .map(|file| {
-            file.lines()
-                .filter(|line| !line.is_empty())
-                .map(parse_line)
-                .collect()
-        })
-        .expect("Unable to open file");
-    println!("{}", build_circuits(boxes))
-}
-
-#[derive(PartialEq, Eq, Hash, PartialOrd, Debug)]
-struct Pos {
-    pub x: u32,
-    pub y: u32,
-    pub z: u32,
-}
-
-fn parse_line(line: &str) -> Pos {
-    let parts: Vec<u32> = line
-        .split(",")
-        .map(|num| num.parse().expect("unable to parse number"))
-        .collect();
-    Pos {
-        x: parts[0],
-        y: parts[1],
-        z: parts[2],
-    }
-}
-
-fn distance(pos1: &Pos, pos2: &Pos) -> f32 {
-    (((pos1.x as i64 - pos2.x as i64).pow(2)
-        + (pos1.y as i64 - pos2.y as i64).pow(2)
-        + (pos1.z as i64 - pos2.z as i64).pow(2)) as f32)
-        .sqrt()
-}
-
-fn calc_distances(boxes: Vec<Pos>) -> Vec<(f32, Rc<Pos>, Rc<Pos>)> {
-    let rc_boxes: Vec<Rc<Pos>> = boxes.into_iter().map(Rc::new).collect();
-    let mut out_boxes: Vec<(f32, Rc<Pos>, Rc<Pos>)> = rc_boxes
-        .iter()
-        .enumerate()
-        .flat_map(|(i, box1)| {
-            rc_boxes
-                .iter()
-                .skip(i + 1)
-                .map(|box2| {
-                    if box1 < box2 {
-                        (distance(box1, box2), box1.clone(), box2.clone())
-                    } else {
-                        (distance(box2, box1), box2.clone(), box1.clone())
-                    }
-                })
-                .collect::<Vec<(f32, Rc<Pos>, Rc<Pos>)>>()
-        })
-        .collect();
-    out_boxes.sort_by(|a, b| b.partial_cmp(a).unwrap());
-    out_boxes
-}
-
-fn build_circuits(boxes: Vec<Pos>) -> u64 {
+pub fn build_circuits(boxes: Vec<Pos>) -> u64 {
     let mut box_distances = calc_distances(boxes);
     let mut circuits: HashMap<Rc<Pos>, u32> = HashMap::with_capacity(box_distances.len());
     let mut circuit_counter: u32 = 0;
@@ -99,15 +38,13 @@ fn build_circuits(boxes: Vec<Pos>) -> u64 {
             circuits.insert(key, value);
         });
     }
-    {
-        println!("{{");
+    if log::log_enabled!(log::Level::Trace) {
         let mut sorted_circuits: Vec<(&Rc<Pos>, &u32)> = circuits.iter().collect();
         sorted_circuits
             .sort_by(|(pos1, i1), (pos2, i2)| (i1, pos1).partial_cmp(&(i2, pos2)).unwrap());
         for (pos, i) in sorted_circuits {
-            println!("\t{}: {:?}", i, pos);
+            log::trace!("Circuit {}: {:?}", i, pos);
         }
-        println!("}}");
     }
 
     if let Some((b1, b2)) = most_recent_connected {
@@ -121,107 +58,6 @@ fn build_circuits(boxes: Vec<Pos>) -> u64 {
 mod test {
     use super::*;
 
-    #[test]
-    fn test_parse_line() {
-        assert_eq!(
-            parse_line("162,817,812"),
-            Pos {
-                x: 162,
-                y: 817,
-                z: 812
-            }
-        )
-    }
-
-    #[test]
-    fn test_calc_distances() {
-        assert_eq!(
-            calc_distances(vec![
-                Pos {
-                    x: 162,
-                    y: 817,
-                    z: 812
-                },
-                Pos {
-                    x: 425,
-                    y: 690,
-                    z: 689
-                },
-                Pos {
-                    x: 431,
-                    y: 825,
-                    z: 988
-                },
-                Pos {
-                    x: 906,
-                    y: 360,
-                    z: 560
-                },
-                Pos {
-                    x: 805,
-                    y: 96,
-                    z: 715
-                },
-            ])
-            .into_iter()
-            .rev()
-            .take(4)
-            .rev()
-            .map(|(_, box1, box2)| (box1, box2))
-            .collect::<Vec<(Rc<Pos>, Rc<Pos>)>>(),
-            vec![
-                (
-                    Rc::new(Pos {
-                        x: 425,
-                        y: 690,
-                        z: 689
-                    }),
-                    Rc::new(Pos {
-                        x: 431,
-                        y: 825,
-                        z: 988
-                    })
-                ),
-                (
-                    Rc::new(Pos {
-                        x: 805,
-                        y: 96,
-                        z: 715
-                    }),
-                    Rc::new(Pos {
-                        x: 906,
-                        y: 360,
-                        z: 560
-                    })
-                ),
-                (
-                    Rc::new(Pos {
-                        x: 162,
-                        y: 817,
-                        z: 812
-                    }),
-                    Rc::new(Pos {
-                        x: 431,
-                        y: 825,
-                        z: 988
-                    })
-                ),
-                (
-                    Rc::new(Pos {
-                        x: 162,
-                        y: 817,
-                        z: 812
-                    }),
-                    Rc::new(Pos {
-                        x: 425,
-                        y: 690,
-                        z: 689
-                    })
-                )
-            ]
-        )
-    }
-
     #[test]
     fn test_build_circuits() {
         assert_eq!(
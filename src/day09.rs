@@ -0,0 +1,57 @@
+use crate::Solution;
+use crate::error::{ParseError, parse_non_empty_lines, parse_numbers};
+use std::cmp::PartialOrd;
+
+pub mod prob1;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Pos>;
+    type Output = u64;
+
+    const EXAMPLE: &'static str = "\
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+";
+
+    fn parse(input: &str) -> Result<Vec<Pos>, ParseError> {
+        parse_non_empty_lines(input, parse_line)
+    }
+
+    fn part1(tiles: Vec<Pos>) -> u64 {
+        prob1::calc_sizes(tiles)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct Pos {
+    pub x: u32,
+    pub y: u32,
+}
+
+pub fn parse_line(line: &str) -> Result<Pos, ParseError> {
+    let [x, y] = parse_numbers(line, line, ",")?;
+    Ok(Pos { x, y })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(parse_line("7,1"), Ok(Pos { x: 7, y: 1 }))
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day09::part1(Day09::parse(Day09::EXAMPLE).unwrap()), 50);
+    }
+}
@@ -0,0 +1,250 @@
+use crate::Solution;
+use crate::error::{ParseError, parse_non_empty_lines};
+
+pub mod prob1;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Machine>;
+    type Output = usize;
+
+    const EXAMPLE: &'static str = "\
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
+";
+
+    fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+        parse_non_empty_lines(input, parse_line)
+    }
+
+    fn part1(machines: Vec<Machine>) -> usize {
+        prob1::turn_on_all(machines)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Machine {
+    pub indicators: u16,
+    pub schematics: Vec<u16>,
+    pub requirements: Vec<u32>,
+}
+
+#[inline]
+pub fn get_bitmask_bool(mask: &[bool]) -> u16 {
+    let mut mask_val: u16 = 0;
+    for (i, val) in mask.iter().enumerate() {
+        if *val {
+            mask_val |= 2u16.pow(i as u32);
+        }
+    }
+    mask_val
+}
+
+#[inline]
+pub fn get_bitmask_indexes(mask: &[usize]) -> u16 {
+    let mut mask_val: u16 = 0;
+    for i in mask.iter() {
+        mask_val |= 2u16.pow(*i as u32)
+    }
+    mask_val
+}
+
+/// Parse the comma-separated numbers captured between a pair of brackets, reporting errors at the
+/// column just after the opening bracket
+fn parse_bracket_nums<T: std::str::FromStr>(
+    line: &str,
+    buffer: &[char],
+    open_i: usize,
+) -> Result<Vec<T>, ParseError> {
+    let val: String = buffer.iter().collect();
+    val.split(",")
+        .map(|v| {
+            v.parse()
+                .map_err(|_| ParseError::new(line, open_i + 2, "a comma-separated list of numbers"))
+        })
+        .collect()
+}
+
+pub fn parse_line(line: &str) -> Result<Machine, ParseError> {
+    let mut indicators: Vec<bool> = Vec::new();
+    let mut schematics: Vec<Vec<usize>> = Vec::new();
+    let mut requirements: Vec<u32> = Vec::new();
+
+    let mut in_indicators = false;
+    let mut in_schematic = false;
+    let mut in_requirements = false;
+    let mut buffer: Vec<char> = Vec::new();
+    let mut open_i: usize = 0;
+    for (i, char) in line.chars().enumerate() {
+        if char == ' ' {
+            continue;
+        }
+
+        if (if in_indicators { 1 } else { 0 })
+            + (if in_schematic { 1 } else { 0 })
+            + (if in_requirements { 1 } else { 0 })
+            > 1
+        {
+            panic!(
+                "Invalid state:\n\tin_indicators = {}\n\tin_schematic = {}\n\tin_requirements = {}",
+                in_indicators, in_schematic, in_requirements
+            )
+        }
+
+        if !in_indicators && !in_schematic && !in_requirements {
+            open_i = i;
+            match char {
+                '[' => in_indicators = true,
+                '(' => in_schematic = true,
+                '{' => in_requirements = true,
+                _ => return Err(ParseError::new(line, i + 1, "'[', '(' or '{'")),
+            }
+        } else if in_indicators {
+            if char == ']' {
+                for item in buffer.iter() {
+                    indicators.push(*item == '#');
+                }
+                if indicators.len() > u16::BITS as usize {
+                    return Err(ParseError::new(
+                        line,
+                        open_i + 2,
+                        format!("at most {} indicators", u16::BITS),
+                    ));
+                }
+
+                buffer.clear();
+                in_indicators = false;
+            } else {
+                buffer.push(char);
+            }
+        } else if in_schematic {
+            if char == ')' {
+                let nums: Vec<usize> = parse_bracket_nums(line, &buffer, open_i)?;
+                if nums.iter().any(|num| *num >= u16::BITS as usize) {
+                    return Err(ParseError::new(
+                        line,
+                        open_i + 2,
+                        format!("indicator indexes below {}", u16::BITS),
+                    ));
+                }
+                schematics.push(nums);
+
+                buffer.clear();
+                in_schematic = false;
+            } else {
+                buffer.push(char);
+            }
+        } else if in_requirements {
+            if char == '}' {
+                requirements.extend(parse_bracket_nums::<u32>(line, &buffer, open_i)?);
+
+                buffer.clear();
+                in_requirements = false;
+            } else {
+                buffer.push(char);
+            }
+        }
+    }
+
+    if in_indicators || in_schematic || in_requirements {
+        let close = if in_indicators {
+            ']'
+        } else if in_schematic {
+            ')'
+        } else {
+            '}'
+        };
+        return Err(ParseError::new(
+            line,
+            line.chars().count() + 1,
+            format!("a closing '{}'", close),
+        ));
+    }
+
+    Ok(Machine {
+        indicators: get_bitmask_bool(&indicators),
+        schematics: schematics
+            .into_iter()
+            .map(|val| get_bitmask_indexes(&val))
+            .collect(),
+        requirements,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_bitmask_bool() {
+        assert_eq!(get_bitmask_bool(&[true, true, false, false]), 0b0011)
+    }
+
+    #[test]
+    fn test_get_bitmask_indexes() {
+        assert_eq!(get_bitmask_indexes(&[2, 3]), 0b1100)
+    }
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"),
+            Ok(Machine {
+                indicators: 0b0110,
+                schematics: vec![0b1000, 0b1010, 0b0100, 0b1100, 0b0101, 0b0011],
+                requirements: vec![3, 5, 4, 7]
+            })
+        )
+    }
+
+    #[test]
+    fn test_parse_line_unexpected_char() {
+        assert_eq!(
+            parse_line("[.##.] (3) <1> {3,5}"),
+            Err(ParseError::new(
+                "[.##.] (3) <1> {3,5}",
+                12,
+                "'[', '(' or '{'"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_line_bad_number() {
+        assert_eq!(
+            parse_line("[.##.] (3) (1,x) {3,5}"),
+            Err(ParseError::new(
+                "[.##.] (3) (1,x) {3,5}",
+                13,
+                "a comma-separated list of numbers"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_line_unclosed() {
+        assert_eq!(
+            parse_line("[.##.] (3) {3,5"),
+            Err(ParseError::new("[.##.] (3) {3,5", 16, "a closing '}'"))
+        )
+    }
+
+    #[test]
+    fn test_parse_line_too_many_indicators() {
+        assert_eq!(
+            parse_line("[................#] (1) {3}"),
+            Err(ParseError::new(
+                "[................#] (1) {3}",
+                2,
+                "at most 16 indicators"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day10::part1(Day10::parse(Day10::EXAMPLE).unwrap()), 7);
+    }
+}
@@ -0,0 +1,108 @@
+use super::Machine;
+use std::collections::VecDeque;
+
+#[derive(Debug)]
+struct SolState {
+    cost: usize,
+    indicators: u16,
+}
+
+pub fn turn_on_machine(machine: Machine) -> usize {
+    let mut queue: VecDeque<SolState> = VecDeque::new();
+    queue.push_back(SolState {
+        cost: 0,
+        indicators: 0,
+    });
+
+    while let Some(SolState { cost, indicators }) = queue.pop_front() {
+        if indicators == machine.indicators {
+            return cost;
+        }
+        for schematic in machine.schematics.iter() {
+            let new_indicators = indicators ^ schematic;
+            queue.push_back(SolState {
+                cost: cost + 1,
+                indicators: new_indicators,
+            });
+        }
+    }
+
+    panic!("Unable to find solution!")
+}
+
+pub fn turn_on_all(machines: Vec<Machine>) -> usize {
+    machines
+        .into_iter()
+        .enumerate()
+        .map(|(i, machine)| {
+            let count = turn_on_machine(machine);
+            log::debug!("Solved machine {}, result {}", i, count);
+            count
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_turn_on_machine_1() {
+        assert_eq!(
+            turn_on_machine(Machine {
+                indicators: 0b0110,
+                schematics: vec![0b1000, 0b1010, 0b0100, 0b1100, 0b0101, 0b0011],
+                requirements: vec![3, 5, 4, 7]
+            }),
+            2
+        )
+    }
+
+    #[test]
+    fn test_turn_on_machine_2() {
+        assert_eq!(
+            turn_on_machine(Machine {
+                indicators: 0b01000,
+                schematics: vec![0b11101, 0b01100, 0b10001, 0b00111, 0b11110],
+                requirements: vec![7, 5, 12, 7, 2]
+            }),
+            3
+        )
+    }
+
+    #[test]
+    fn test_turn_on_machine_3() {
+        assert_eq!(
+            turn_on_machine(Machine {
+                indicators: 0b101110,
+                schematics: vec![0b011111, 0b011001, 0b110111, 0b000110],
+                requirements: vec![10, 11, 11, 5, 10, 5]
+            }),
+            2
+        )
+    }
+
+    #[test]
+    fn test_turn_on_all() {
+        assert_eq!(
+            turn_on_all(vec![
+                Machine {
+                    indicators: 0b0110,
+                    schematics: vec![0b1000, 0b1010, 0b0100, 0b1100, 0b0101, 0b0011],
+                    requirements: vec![3, 5, 4, 7]
+                },
+                Machine {
+                    indicators: 0b01000,
+                    schematics: vec![0b11101, 0b01100, 0b10001, 0b00111, 0b11110,],
+                    requirements: vec![7, 5, 12, 7, 2]
+                },
+                Machine {
+                    indicators: 0b101110,
+                    schematics: vec![0b011111, 0b011001, 0b110111, 0b000110,],
+                    requirements: vec![10, 11, 11, 5, 10, 5]
+                }
+            ]),
+            7
+        )
+    }
+}
@@ -1,30 +1,81 @@
-use std::collections::{HashMap, VecDeque};
+use crate::Solution;
+use crate::error::ParseError;
+use std::collections::HashMap;
 
-fn main() {
-    let network = std::fs::read_to_string("src/bin/day11.txt")
-        .map(|file| parse_lines(file.lines().collect()))
-        .expect("Unable to open file");
-    println!("{}", find_paths(network))
+pub mod prob1;
+pub mod prob2;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Network;
+    type Output = usize;
+
+    const EXAMPLE: &'static str = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+";
+
+    const EXAMPLE_PART2: &'static str = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        parse_lines(input.lines().collect())
+    }
+
+    fn part1(network: Network) -> usize {
+        prob1::find_paths(network)
+    }
+
+    fn part2(network: Network) -> Option<usize> {
+        Some(prob2::find_paths(network))
+    }
 }
 
-#[derive(PartialEq, Eq, Debug)]
-struct Network {
-    node_ids: HashMap<String, u16>,
-    connections: HashMap<u16, Vec<u16>>,
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Network {
+    pub node_ids: HashMap<String, usize>,
+    pub connections: HashMap<usize, Vec<usize>>,
 }
 
 #[allow(clippy::unnecessary_to_owned)]
-fn parse_lines(lines: Vec<&str>) -> Network {
+pub fn parse_lines(lines: Vec<&str>) -> Result<Network, ParseError> {
     let raw_map: Vec<(&str, Vec<&str>)> = lines
         .into_iter()
-        .map(|line| {
-            let parts: Vec<&str> = line.split(":").collect();
-            (parts[0], parts[1].trim().split(" ").collect())
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            let (source, sinks) = line.split_once(":").ok_or_else(|| {
+                ParseError::new(line, line.chars().count() + 1, "':' after the node name")
+                    .at_line(i + 1)
+            })?;
+            Ok((source, sinks.trim().split(" ").collect()))
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
     let mut curr_id = 0;
-    let mut node_ids: HashMap<String, u16> = HashMap::new();
+    let mut node_ids: HashMap<String, usize> = HashMap::new();
     for (source_node, sink_nodes) in raw_map.iter() {
         if let std::collections::hash_map::Entry::Vacant(e) =
             node_ids.entry(source_node.to_string())
@@ -43,7 +94,7 @@ fn parse_lines(lines: Vec<&str>) -> Network {
         }
     }
 
-    let connections: HashMap<u16, Vec<u16>> = raw_map
+    let connections: HashMap<usize, Vec<usize>> = raw_map
         .iter()
         .map(|(source_node, sink_nodes)| {
             (
@@ -62,31 +113,10 @@ fn parse_lines(lines: Vec<&str>) -> Network {
         })
         .collect();
 
-    Network {
+    Ok(Network {
         node_ids,
         connections,
-    }
-}
-
-#[allow(clippy::unnecessary_to_owned)]
-fn find_paths(network: Network) -> usize {
-    let mut queue: VecDeque<u16> = VecDeque::new();
-    queue.push_back(*network.node_ids.get(&"you".to_string()).unwrap());
-
-    let target_node_id = *network.node_ids.get(&"out".to_string()).unwrap();
-    let mut path_count: usize = 0;
-
-    while let Some(node_id) = queue.pop_front() {
-        if node_id == target_node_id {
-            path_count += 1;
-        } else {
-            for next_node_id in network.connections.get(&node_id).unwrap() {
-                queue.push_back(*next_node_id);
-            }
-        }
-    }
-
-    path_count
+    })
 }
 
 #[cfg(test)]
@@ -109,7 +139,7 @@ mod test {
                 "hhh: ccc fff iii",
                 "iii: out",
             ]),
-            Network {
+            Ok(Network {
                 node_ids: hashmap! {
                     "aaa".to_string() => 0,
                     "you".to_string() => 1,
@@ -135,41 +165,24 @@ mod test {
                     2 => vec![4, 7, 10],
                     10 => vec![9],
                 }
-            }
+            })
         )
     }
 
     #[test]
-    fn test_calc_sizes() {
+    fn test_parse_lines_missing_colon() {
         assert_eq!(
-            find_paths(Network {
-                node_ids: hashmap! {
-                    "aaa".to_string() => 0,
-                    "you".to_string() => 1,
-                    "hhh".to_string() => 2,
-                    "bbb".to_string() => 3,
-                    "ccc".to_string() => 4,
-                    "ddd".to_string() => 5,
-                    "eee".to_string() => 6,
-                    "fff".to_string() => 7,
-                    "ggg".to_string() => 8,
-                    "out".to_string() => 9,
-                    "iii".to_string() => 10,
-                },
-                connections: hashmap! {
-                    0 => vec![1, 2],
-                    1 => vec![3, 4],
-                    3 => vec![5, 6],
-                    4 => vec![5, 6, 7],
-                    5 => vec![8],
-                    6 => vec![9],
-                    7 => vec![9],
-                    8 => vec![9],
-                    2 => vec![4, 7, 10],
-                    10 => vec![9],
-                }
-            }),
-            5
+            parse_lines(vec!["aaa: you hhh", "you bbb ccc"]),
+            Err(ParseError::new("you bbb ccc", 12, "':' after the node name").at_line(2))
         )
     }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day11::part1(Day11::parse(Day11::EXAMPLE).unwrap()), 5);
+        assert_eq!(
+            Day11::part2(Day11::parse(Day11::EXAMPLE_PART2).unwrap()),
+            Some(2)
+        );
+    }
 }
@@ -0,0 +1,63 @@
+use super::Network;
+use std::collections::VecDeque;
+
+#[allow(clippy::unnecessary_to_owned)]
+pub fn find_paths(network: Network) -> usize {
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    queue.push_back(*network.node_ids.get(&"you".to_string()).unwrap());
+
+    let target_node_id = *network.node_ids.get(&"out".to_string()).unwrap();
+    let mut path_count: usize = 0;
+
+    while let Some(node_id) = queue.pop_front() {
+        if node_id == target_node_id {
+            path_count += 1;
+        } else {
+            for next_node_id in network.connections.get(&node_id).unwrap() {
+                queue.push_back(*next_node_id);
+            }
+        }
+    }
+
+    path_count
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use maplit::hashmap;
+
+    #[test]
+    fn test_calc_sizes() {
+        assert_eq!(
+            find_paths(Network {
+                node_ids: hashmap! {
+                    "aaa".to_string() => 0,
+                    "you".to_string() => 1,
+                    "hhh".to_string() => 2,
+                    "bbb".to_string() => 3,
+                    "ccc".to_string() => 4,
+                    "ddd".to_string() => 5,
+                    "eee".to_string() => 6,
+                    "fff".to_string() => 7,
+                    "ggg".to_string() => 8,
+                    "out".to_string() => 9,
+                    "iii".to_string() => 10,
+                },
+                connections: hashmap! {
+                    0 => vec![1, 2],
+                    1 => vec![3, 4],
+                    3 => vec![5, 6],
+                    4 => vec![5, 6, 7],
+                    5 => vec![8],
+                    6 => vec![9],
+                    7 => vec![9],
+                    8 => vec![9],
+                    2 => vec![4, 7, 10],
+                    10 => vec![9],
+                }
+            }),
+            5
+        )
+    }
+}
@@ -0,0 +1,98 @@
+use super::Network;
+use std::collections::HashMap;
+
+fn _find_paths(
+    network: &Network,
+    node_id: usize,
+    target_node_id: usize,
+    cache: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    let key = (node_id, target_node_id);
+    if cache.contains_key(&key) {
+        *cache.get(&key).unwrap()
+    } else {
+        let new_val = if node_id == target_node_id {
+            1
+        } else {
+            network
+                .connections
+                .get(&node_id)
+                .unwrap_or(&vec![])
+                .iter()
+                .map(|next_node_id| _find_paths(network, *next_node_id, target_node_id, cache))
+                .sum()
+        };
+
+        cache.insert(key, new_val);
+        new_val
+    }
+}
+
+#[allow(clippy::unnecessary_to_owned)]
+pub fn find_paths(network: Network) -> usize {
+    let svr_i = *network.node_ids.get(&"svr".to_string()).unwrap();
+    let dac_i = *network.node_ids.get(&"dac".to_string()).unwrap();
+    let fft_i = *network.node_ids.get(&"fft".to_string()).unwrap();
+    let out_i = *network.node_ids.get(&"out".to_string()).unwrap();
+
+    let mut cache: HashMap<(usize, usize), usize> = HashMap::new();
+    let svr_to_dac = _find_paths(&network, svr_i, dac_i, &mut cache);
+    log::debug!("svr_to_dac = {}", svr_to_dac);
+    let dac_to_fft = _find_paths(&network, dac_i, fft_i, &mut cache);
+    log::debug!("dac_to_fft = {}", dac_to_fft);
+    let fft_to_out = _find_paths(&network, fft_i, out_i, &mut cache);
+    log::debug!("fft_to_out = {}", fft_to_out);
+    let svr_to_fft = _find_paths(&network, svr_i, fft_i, &mut cache);
+    log::debug!("svr_to_fft = {}", svr_to_fft);
+    let fft_to_dac = _find_paths(&network, fft_i, dac_i, &mut cache);
+    log::debug!("fft_to_dac = {}", fft_to_dac);
+    let dac_to_out = _find_paths(&network, dac_i, out_i, &mut cache);
+    log::debug!("dac_to_out = {}", dac_to_out);
+    (svr_to_dac * dac_to_fft * fft_to_out) + (svr_to_fft * fft_to_dac * dac_to_out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use maplit::hashmap;
+
+    #[test]
+    fn test_calc_sizes() {
+        assert_eq!(
+            find_paths(Network {
+                node_ids: hashmap! {
+                    "svr".to_string() => 0,
+                    "aaa".to_string() => 1,
+                    "bbb".to_string() => 2,
+                    "fft".to_string() => 3,
+                    "ccc".to_string() => 4,
+                    "tty".to_string() => 5,
+                    "ddd".to_string() => 6,
+                    "eee".to_string() => 7,
+                    "hub".to_string() => 8,
+                    "fff".to_string() => 9,
+                    "dac".to_string() => 10,
+                    "ggg".to_string() => 11,
+                    "hhh".to_string() => 12,
+                    "out".to_string() => 13,
+                },
+                connections: hashmap! {
+                    0 => vec![1, 2],
+                    1 => vec![3],
+                    3 => vec![4],
+                    2 => vec![5],
+                    5 => vec![4],
+                    4 => vec![6, 7],
+                    6 => vec![8],
+                    8 => vec![9],
+                    7 => vec![10],
+                    10 => vec![9],
+                    9 => vec![11, 12],
+                    11 => vec![13],
+                    12 => vec![13],
+                }
+            }),
+            2
+        )
+    }
+}
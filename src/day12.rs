@@ -0,0 +1,223 @@
+use crate::Solution;
+use crate::error::{ParseError, parse_number, parse_numbers};
+use crate::grid::Grid;
+
+pub mod prob1;
+
+pub struct Day12;
+
+/// The dimensions of a region under the tree and the number of each present shape to fit in it
+pub type Region = ((usize, usize), Vec<usize>);
+
+/// The shapes of the presents, where `true` marks a part of the shape, and the regions to fit them
+/// into
+#[derive(Clone, PartialEq, Debug)]
+pub struct Presents {
+    pub shapes: Vec<Grid<bool>>,
+    pub regions: Vec<Region>,
+}
+
+impl Solution for Day12 {
+    type Input = Presents;
+    type Output = usize;
+
+    const EXAMPLE: &'static str = "\
+0:
+###
+##.
+##.
+
+1:
+###
+##.
+.##
+
+2:
+.##
+###
+##.
+
+3:
+##.
+###
+##.
+
+4:
+###
+#..
+###
+
+5:
+###
+.#.
+###
+
+4x4: 0 0 0 0 2 0
+12x5: 1 0 1 0 2 2
+12x5: 1 0 1 0 3 2
+";
+
+    fn parse(input: &str) -> Result<Presents, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        Ok(Presents {
+            shapes: parse_shapes(lines.clone())?,
+            regions: parse_lines(lines)?,
+        })
+    }
+
+    fn part1(presents: Presents) -> usize {
+        prob1::sanity_check(presents.regions)
+    }
+}
+
+/// Parse a region line of the form `12x5: 1 0 1 0 2 2`
+fn parse_region(line: &str) -> Result<Region, ParseError> {
+    let (raw_dims, raw_counts) = line.split_once(": ").ok_or_else(|| {
+        ParseError::new(line, line.chars().count() + 1, "': ' after the dimensions")
+    })?;
+    let [width, height] = parse_numbers(line, raw_dims, "x")?;
+    let counts: Vec<usize> = raw_counts
+        .split(" ")
+        .map(|count| parse_number(line, count))
+        .collect::<Result<_, _>>()?;
+    Ok(((width, height), counts))
+}
+
+/// Parse the shape blocks, each a line of the form `0:` followed by a map of `#` and `.`
+pub fn parse_shapes(lines: Vec<&str>) -> Result<Vec<Grid<bool>>, ParseError> {
+    let mut shapes: Vec<Grid<bool>> = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        if line.contains("x") || !line.ends_with(":") {
+            i += 1;
+            continue;
+        }
+
+        let index_raw = &line[..line.len() - 1];
+        let index: usize = parse_number(line, index_raw)?;
+        if index != shapes.len() {
+            return Err(ParseError::new(line, 1, format!("shape {}", shapes.len())).at_line(i + 1));
+        }
+
+        let end = lines[i + 1..]
+            .iter()
+            .position(|line| line.is_empty())
+            .map_or(lines.len(), |len| i + 1 + len);
+        let shape = Grid::parse(
+            &lines[i + 1..end].join("\n"),
+            |char| match char {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            },
+            "'#' or '.'",
+        )
+        // the map starts on the line after the shape's index
+        .map_err(|err| {
+            let line = err.line + i + 1;
+            err.at_line(line)
+        })?;
+        shapes.push(shape);
+        i = end;
+    }
+    Ok(shapes)
+}
+
+pub fn parse_lines(lines: Vec<&str>) -> Result<Vec<Region>, ParseError> {
+    lines
+        .into_iter()
+        .enumerate()
+        .filter(|(_, line)| line.contains("x"))
+        .map(|(i, line)| parse_region(line).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines(vec![
+                "0:",
+                "###",
+                "##.",
+                "##.",
+                "",
+                "1:",
+                "###",
+                "##.",
+                ".##",
+                "",
+                "2:",
+                ".##",
+                "###",
+                "##.",
+                "",
+                "3:",
+                "##.",
+                "###",
+                "##.",
+                "",
+                "4:",
+                "###",
+                "#..",
+                "###",
+                "",
+                "5:",
+                "###",
+                ".#.",
+                "###",
+                "",
+                "4x4: 0 0 0 0 2 0",
+                "12x5: 1 0 1 0 2 2",
+                "12x5: 1 0 1 0 3 2",
+            ]),
+            Ok(vec![
+                ((4, 4), vec![0, 0, 0, 0, 2, 0]),
+                ((12, 5), vec![1, 0, 1, 0, 2, 2]),
+                ((12, 5), vec![1, 0, 1, 0, 3, 2]),
+            ])
+        )
+    }
+
+    #[test]
+    fn test_parse_shapes() {
+        let t = true;
+        let f = false;
+        assert_eq!(
+            parse_shapes(vec![
+                "0:", "###", "#..", "", "1:", ".#", "##", "", "4x4: 1 1"
+            ]),
+            Ok(vec![
+                Grid::from_rows(vec![vec![t, t, t], vec![t, f, f]]),
+                Grid::from_rows(vec![vec![f, t], vec![t, t]]),
+            ])
+        )
+    }
+
+    #[test]
+    fn test_parse_shapes_bad_char() {
+        assert_eq!(
+            parse_shapes(vec!["0:", "###", "", "1:", "##", "#x"]),
+            Err(ParseError::new("#x", 2, "'#' or '.'").at_line(6))
+        )
+    }
+
+    #[test]
+    fn test_parse_lines_bad_count() {
+        assert_eq!(
+            parse_lines(vec!["0:", "###", "", "4x4: 0 y"]),
+            Err(ParseError::new("4x4: 0 y", 8, "a number").at_line(4))
+        )
+    }
+
+    #[test]
+    fn test_example() {
+        let presents = Day12::parse(Day12::EXAMPLE).unwrap();
+        assert_eq!(presents.shapes.len(), 6);
+        assert_eq!(presents.regions.len(), 3);
+    }
+}
@@ -0,0 +1,10 @@
+use super::Region;
+
+/// Count the regions that the presents fit into even without interlocking them, by giving every
+/// present a whole 3x3 box
+pub fn sanity_check(regions: Vec<Region>) -> usize {
+    regions
+        .into_iter()
+        .filter(|((x, y), counts)| counts.iter().sum::<usize>() * 9 <= x * y)
+        .count()
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// An error encountered while parsing puzzle input, pointing at the offending token so that it can
+/// be reported with a caret-annotated snippet of the bad line
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    /// The name of the input being parsed, if known
+    pub input: Option<String>,
+    /// The 1-based number of the offending line
+    pub line: usize,
+    /// The 1-based column of the offending token within the line
+    pub column: usize,
+    /// A description of what was expected at the offending position
+    pub expected: String,
+    /// The text of the offending line
+    pub text: String,
+}
+
+impl ParseError {
+    /// Create an error at the 1-based `column` of the line `text`.  The line number defaults to 1
+    /// and should be filled in with [`ParseError::at_line`] by whatever split the input into lines.
+    pub fn new(text: &str, column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            input: None,
+            line: 1,
+            column,
+            expected: expected.into(),
+            text: text.to_string(),
+        }
+    }
+
+    /// Create an error pointing at the start of `token`, which must be a slice of `text`
+    pub fn at_token(text: &str, token: &str, expected: impl Into<String>) -> ParseError {
+        let offset = token.as_ptr() as usize - text.as_ptr() as usize;
+        debug_assert!(offset <= text.len(), "token must be a slice of text");
+        ParseError::new(text, text[..offset].chars().count() + 1, expected)
+    }
+
+    /// Set the 1-based number of the line the error occurred on
+    pub fn at_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        self
+    }
+
+    /// Set the name of the input the error occurred in
+    pub fn in_input(mut self, input: &str) -> ParseError {
+        self.input = Some(input.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "error: expected {}", self.expected)?;
+        writeln!(
+            f,
+            "{}--> {}:{}:{}",
+            gutter,
+            self.input.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse `token`, which must be a slice of the line `text`, as a number
+pub fn parse_number<T: FromStr>(text: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at_token(text, token, "a number"))
+}
+
+/// Parse `field`, which must be a slice of the line `text`, as exactly `N` numbers joined by
+/// `separator`, such as `3-5` or `162,817,812`
+pub fn parse_numbers<T: FromStr, const N: usize>(
+    text: &str,
+    field: &str,
+    separator: &str,
+) -> Result<[T; N], ParseError> {
+    let expected = || format!("{} numbers separated by '{}'", N, separator);
+    let mut nums: Vec<T> = Vec::with_capacity(N);
+    let mut parts = field.split(separator);
+    for _ in 0..N {
+        let part = parts
+            .next()
+            .ok_or_else(|| ParseError::at_token(text, &field[field.len()..], expected()))?;
+        nums.push(parse_number(text, part)?);
+    }
+    if let Some(extra) = parts.next() {
+        // point at the separator that introduced the unexpected extra number
+        let offset = extra.as_ptr() as usize - field.as_ptr() as usize - separator.len();
+        return Err(ParseError::at_token(text, &field[offset..], expected()));
+    }
+
+    match nums.try_into() {
+        Ok(nums) => Ok(nums),
+        Err(_) => unreachable!("exactly N numbers were parsed"),
+    }
+}
+
+/// Parse every non-empty line of `input` with `parse_line`, recording the line number of the first
+/// line that fails to parse
+pub fn parse_non_empty_lines<T>(
+    input: &str,
+    parse_line: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse_line(line).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_at_token() {
+        let line = "12,x4,9";
+        assert_eq!(
+            ParseError::at_token(line, &line[3..5], "a number").column,
+            4
+        )
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ParseError::new("L1x", 3, "a number")
+                .at_line(12)
+                .in_input("day01.txt")
+                .to_string(),
+            "error: expected a number\n  --> day01.txt:12:3\n   |\n12 | L1x\n   |   ^"
+        )
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(
+            parse_numbers::<u32, 3>("1,2,3", "1,2,3", ","),
+            Ok([1, 2, 3])
+        )
+    }
+
+    #[test]
+    fn test_parse_numbers_bad_number() {
+        assert_eq!(
+            parse_numbers::<u32, 2>("5-x", "5-x", "-"),
+            Err(ParseError::new("5-x", 3, "a number"))
+        )
+    }
+
+    #[test]
+    fn test_parse_numbers_too_few() {
+        assert_eq!(
+            parse_numbers::<u32, 3>("1,2", "1,2", ","),
+            Err(ParseError::new("1,2", 4, "3 numbers separated by ','"))
+        )
+    }
+
+    #[test]
+    fn test_parse_numbers_too_many() {
+        let line = "a 1-2-3";
+        assert_eq!(
+            parse_numbers::<u32, 2>(line, &line[2..], "-"),
+            Err(ParseError::new("a 1-2-3", 6, "2 numbers separated by '-'"))
+        )
+    }
+
+    #[test]
+    fn test_parse_non_empty_lines() {
+        assert_eq!(
+            parse_non_empty_lines("1\n\nx", |line| parse_number::<u8>(line, line)),
+            Err(ParseError::new("x", 1, "a number").at_line(3))
+        )
+    }
+}
@@ -0,0 +1,391 @@
+use crate::error::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular 2D grid of cells stored row-major.  Positions are `(row, column)` pairs with
+/// `(0, 0)` in the top left corner.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// The offsets of the four orthogonal neighbors of a cell
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The offsets of the eight orthogonal and diagonal neighbors of a cell
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+impl<T> Grid<T> {
+    /// Create a grid with every cell set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Create a grid from its rows.  Panics if the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "all rows of a grid must be the same length"
+        );
+        Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Create a grid from rows that may be different lengths, padding short rows with `fill` to
+    /// the length of the longest row
+    pub fn from_ragged_rows(rows: Vec<Vec<T>>, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        Grid::from_rows(
+            rows.into_iter()
+                .map(|mut row| {
+                    row.resize(width, fill.clone());
+                    row
+                })
+                .collect(),
+        )
+    }
+
+    /// Parse a char map, turning each char into a cell with `parse_cell` and describing the chars
+    /// it accepts with `expected`.  Empty lines are skipped, and every other line must be as long
+    /// as the first.
+    pub fn parse(
+        input: &str,
+        parse_cell: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width: Option<usize> = None;
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (line_i, line) in input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+        {
+            let row: Vec<T> = line
+                .chars()
+                .enumerate()
+                .map(|(i, char)| {
+                    parse_cell(char)
+                        .ok_or_else(|| ParseError::new(line, i + 1, expected).at_line(line_i + 1))
+                })
+                .collect::<Result<_, _>>()?;
+
+            let width = *width.get_or_insert(row.len());
+            if row.len() != width {
+                return Err(ParseError::new(
+                    line,
+                    width.min(row.len()) + 1,
+                    format!("a row of {} cells", width),
+                )
+                .at_line(line_i + 1));
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(row, column)`, or `None` if that is outside the grid
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row < self.height && column < self.width {
+            Some(&self.cells[row * self.width + column])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `(row, column)` for updating, or `None` if that is outside the grid
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        if row < self.height && column < self.width {
+            Some(&mut self.cells[row * self.width + column])
+        } else {
+            None
+        }
+    }
+
+    /// Every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// Every cell in the grid along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    fn offset_positions(
+        &self,
+        row: usize,
+        column: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |(d_row, d_column)| {
+            let row = row.checked_add_signed(*d_row)?;
+            let column = column.checked_add_signed(*d_column)?;
+            (row < height && column < width).then_some((row, column))
+        })
+    }
+
+    /// The positions of the up to four cells orthogonally adjacent to `(row, column)`
+    pub fn neighbors4(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.offset_positions(row, column, &OFFSETS_4)
+    }
+
+    /// The positions of the up to eight cells orthogonally or diagonally adjacent to `(row, column)`
+    pub fn neighbors8(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.offset_positions(row, column, &OFFSETS_8)
+    }
+
+    /// The cells of a row, left to right.  Panics if the row is outside the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "row {} is outside the grid", row);
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Every row of the grid, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// The cells of a column, top to bottom.  Panics if the column is outside the grid.
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column {} is outside the grid", column);
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    /// Every column of the grid, left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Build a grid of the same shape by mapping every cell
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Flip the grid over its main diagonal, so that rows become columns
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Rotate the grid a quarter turn clockwise
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self
+                .columns()
+                .flat_map(|column| column.collect::<Vec<&T>>().into_iter().rev())
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Rotate the grid a quarter turn counterclockwise
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|column| self.column(column))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        self.get(row, column)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", row, column))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        self.get_mut(row, column)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", row, column))
+    }
+}
+
+/// Draws the grid with each cell's own `Display` output, a line per row
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn digits() -> Grid<u8> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Grid::parse("#.\n\n.#\n", |char| Some(char == '#'), "'#' or '.'"),
+            Ok(Grid::from_rows(vec![vec![true, false], vec![false, true]]))
+        )
+    }
+
+    #[test]
+    fn test_parse_bad_cell() {
+        assert_eq!(
+            Grid::parse("#.\n.x", |char| (char != 'x').then_some(char), "'#' or '.'"),
+            Err(ParseError::new(".x", 2, "'#' or '.'").at_line(2))
+        )
+    }
+
+    #[test]
+    fn test_parse_short_row() {
+        assert_eq!(
+            Grid::parse("##\n#", Some, "any char"),
+            Err(ParseError::new("#", 2, "a row of 2 cells").at_line(2))
+        )
+    }
+
+    #[test]
+    fn test_from_ragged_rows() {
+        assert_eq!(
+            Grid::from_ragged_rows(vec![vec!['a'], vec!['b', 'c']], ' '),
+            Grid::from_rows(vec![vec!['a', ' '], vec!['b', 'c']])
+        )
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = digits();
+        assert_eq!(grid.get(1, 2), Some(&6));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid[(0, 1)], 2);
+    }
+
+    #[test]
+    fn test_neighbors4() {
+        assert_eq!(
+            digits().neighbors4(0, 1).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 2), (1, 1)]
+        )
+    }
+
+    #[test]
+    fn test_neighbors8() {
+        assert_eq!(
+            digits().neighbors8(1, 0).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(Grid::new(3, 3, 0).neighbors8(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<u8>>(), vec![3, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(
+            digits().transpose(),
+            Grid::from_rows(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+        )
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(
+            digits().rotate_clockwise(),
+            Grid::from_rows(vec![vec![4, 1], vec![5, 2], vec![6, 3]])
+        );
+        assert_eq!(
+            digits().rotate_counterclockwise(),
+            Grid::from_rows(vec![vec![3, 6], vec![2, 5], vec![1, 4]])
+        );
+        assert_eq!(
+            digits()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            digits().rotate_counterclockwise()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(digits().to_string(), "123\n456\n")
+    }
+}
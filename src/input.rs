@@ -0,0 +1,94 @@
+use sha2::{Digest, Sha256};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Where to read a day's puzzle input from
+#[derive(Clone, PartialEq, Debug)]
+pub enum InputSource {
+    /// The committed input for the day, `input/dayNN.txt`
+    Default,
+    /// A file, resolved against the crate root if relative
+    Path(PathBuf),
+    /// Standard input
+    Stdin,
+    /// The example input from the puzzle text
+    Example,
+}
+
+impl InputSource {
+    /// Interpret an `--input` argument, where `-` means standard input
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+}
+
+/// Resolve `path` against the crate root rather than the current working directory, so that the
+/// runner behaves the same no matter where it is launched from
+pub fn resolve(path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+    }
+}
+
+/// The path of the committed puzzle input for a day
+pub fn default_path(day: u8) -> PathBuf {
+    resolve(Path::new(&format!("input/day{:02}.txt", day)))
+}
+
+/// Read all of standard input
+pub fn read_stdin() -> std::io::Result<String> {
+    let mut buffer = String::new();
+    std::io::stdin().read_to_string(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// The hex-encoded SHA-256 digest of an input, identifying exactly which input an answer was
+/// computed from
+pub fn sha256_hex(contents: &str) -> String {
+    format!("{:x}", Sha256::digest(contents.as_bytes()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("day01.txt"),
+            InputSource::Path(PathBuf::from("day01.txt"))
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(
+            resolve(Path::new("input/day01.txt")),
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("input/day01.txt")
+        );
+        assert_eq!(
+            resolve(Path::new("/tmp/day01.txt")),
+            Path::new("/tmp/day01.txt")
+        );
+    }
+
+    #[test]
+    fn test_default_path() {
+        assert!(default_path(1).ends_with("input/day01.txt"));
+    }
+
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
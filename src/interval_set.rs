@@ -0,0 +1,429 @@
+use std::cmp::{max, min};
+use std::fmt::Debug;
+
+/// An integer type that can bound the ranges of an [`IntervalSet`]
+pub trait Endpoint: Copy + Ord + Debug {
+    /// The value one above this one, or `None` if this is the largest value of the type
+    fn checked_next(self) -> Option<Self>;
+    /// The value one below this one, or `None` if this is the smallest value of the type
+    fn checked_prev(self) -> Option<Self>;
+    /// The number of values from `start` to `end` inclusive, which is wide enough to count every
+    /// value of the type
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_endpoint {
+    ($($type:ty),*) => {
+        $(
+            impl Endpoint for $type {
+                fn checked_next(self) -> Option<$type> {
+                    self.checked_add(1)
+                }
+
+                fn checked_prev(self) -> Option<$type> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: $type, end: $type) -> u128 {
+                    (end - start) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_endpoint!(u8, u16, u32, u64, usize);
+
+/// A set of integers stored as a minimal list of inclusive `(start, end)` ranges, sorted by start.
+/// Ranges never overlap or touch, since any that would are merged.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet::default()
+    }
+
+    /// The merged ranges of the set, sorted by start
+    pub fn ranges(&self) -> &[(T, T)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Add every value from `start` to `end` inclusive, merging with any ranges that overlap or
+    /// touch it.  Does nothing if `start` is after `end`.
+    pub fn insert(&mut self, start: T, end: T) {
+        if start > end {
+            return;
+        }
+        // the ranges from `first` up to `last` overlap or touch the new range
+        let first = self
+            .ranges
+            .partition_point(|(_, r_end)| r_end.checked_next().is_some_and(|next| next < start));
+        let last = self
+            .ranges
+            .partition_point(|(r_start, _)| end.checked_next().is_none_or(|next| *r_start <= next));
+
+        let merged = if first < last {
+            (
+                min(start, self.ranges[first].0),
+                max(end, self.ranges[last - 1].1),
+            )
+        } else {
+            (start, end)
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Remove every value from `start` to `end` inclusive, splitting any range that straddles it.
+    /// Does nothing if `start` is after `end`.
+    pub fn remove(&mut self, start: T, end: T) {
+        if start > end {
+            return;
+        }
+        // the ranges from `first` up to `last` overlap the removed range
+        let first = self.ranges.partition_point(|(_, r_end)| *r_end < start);
+        let last = self.ranges.partition_point(|(r_start, _)| *r_start <= end);
+        if first == last {
+            return;
+        }
+
+        let mut remaining: Vec<(T, T)> = Vec::with_capacity(2);
+        if self.ranges[first].0 < start {
+            // start is above the start of a range, so it can't be the smallest value
+            remaining.push((self.ranges[first].0, start.checked_prev().unwrap()));
+        }
+        if self.ranges[last - 1].1 > end {
+            // end is below the end of a range, so it can't be the largest value
+            remaining.push((end.checked_next().unwrap(), self.ranges[last - 1].1));
+        }
+        self.ranges.splice(first..last, remaining);
+    }
+
+    /// Check whether `value` is in the set with a binary search of the ranges
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|(_, end)| *end < value);
+        self.ranges.get(i).is_some_and(|(start, _)| *start <= value)
+    }
+
+    /// The values in either set
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .copied()
+            .collect()
+    }
+
+    /// The values in both sets
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges: Vec<(T, T)> = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_start, a_end) = self.ranges[i];
+            let (b_start, b_end) = other.ranges[j];
+            let (start, end) = (max(a_start, b_start), min(a_end, b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // whichever range ends first can't overlap anything further along the other set
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        // ranges from disjoint, non-touching inputs are themselves disjoint and non-touching
+        IntervalSet { ranges }
+    }
+
+    /// The values in this set but not in `other`, found by walking both sets' ranges together
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges: Vec<(T, T)> = Vec::with_capacity(self.ranges.len());
+        let mut j = 0;
+        for (start, end) in self.ranges.iter().copied() {
+            // ranges of `other` ending before this one can't overlap anything further along
+            while j < other.ranges.len() && other.ranges[j].1 < start {
+                j += 1;
+            }
+            // the start of the part of this range not yet removed, if any is left
+            let mut rest = Some(start);
+            while let Some(rest_start) = rest {
+                let Some((b_start, b_end)) = other.ranges.get(j).copied() else {
+                    break;
+                };
+                if b_start > end {
+                    break;
+                }
+                if b_start > rest_start {
+                    // b_start is above another value, so it can't be the smallest value
+                    ranges.push((rest_start, b_start.checked_prev().unwrap()));
+                }
+                if b_end >= end {
+                    // this range of `other` may reach into the next range of this set
+                    rest = None;
+                } else {
+                    // b_end is below the end of this range, so it can't be the largest value
+                    rest = Some(b_end.checked_next().unwrap());
+                    j += 1;
+                }
+            }
+            if let Some(rest_start) = rest {
+                ranges.push((rest_start, end));
+            }
+        }
+        // pieces of disjoint, non-touching ranges are themselves disjoint and non-touching
+        IntervalSet { ranges }
+    }
+
+    /// The number of values in the set, which is a `u128` so that a set covering every `u64` can
+    /// be measured
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|(start, end)| T::count(*start, *end))
+            .sum()
+    }
+
+    /// The ranges of values between the ranges of the set, in order.  Values below the first range
+    /// and above the last are not included.
+    pub fn gaps(&self) -> impl Iterator<Item = (T, T)> + '_ {
+        // ranges never touch, so there is always at least one value between consecutive ranges
+        self.ranges.windows(2).map(|pair| {
+            (
+                pair[0].1.checked_next().unwrap(),
+                pair[1].0.checked_prev().unwrap(),
+            )
+        })
+    }
+}
+
+/// Build a set from possibly overlapping ranges by sorting them and merging each into the last
+impl<T: Endpoint> FromIterator<(T, T)> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> IntervalSet<T> {
+        let mut sorted: Vec<(T, T)> = iter
+            .into_iter()
+            .filter(|(start, end)| start <= end)
+            .collect();
+        sorted.sort();
+
+        let mut ranges: Vec<(T, T)> = Vec::with_capacity(sorted.len());
+        for (start, end) in sorted.into_iter() {
+            match ranges.last_mut() {
+                Some(last) if last.1.checked_next().is_none_or(|next| start <= next) => {
+                    last.1 = max(last.1, end);
+                }
+                _ => ranges.push((start, end)),
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+/// Proptest strategies for values near the ends of the `u64` domain, shared with the tests of the
+/// days built on interval sets
+#[cfg(test)]
+pub(crate) mod strategy {
+    use proptest::prelude::*;
+
+    /// Random ranges of up to 16 values at one end of the `u64` domain or the other, so that the
+    /// edge values are hit often
+    pub fn edge_ranges() -> impl Strategy<Value = Vec<(u64, u64)>> {
+        let low = (0..48u64, 0..16u64).prop_map(|(start, len)| (start, start + len));
+        let high =
+            (0..48u64, 0..16u64).prop_map(|(end, len)| (u64::MAX - end - len, u64::MAX - end));
+        prop::collection::vec(prop_oneof![low, high], 0..8)
+    }
+
+    /// Random values within 64 of either end of the domain, which can fall in any edge range
+    pub fn edge_values() -> impl Strategy<Value = Vec<u64>> {
+        prop::collection::vec(prop_oneof![0..64u64, u64::MAX - 63..=u64::MAX], 0..32)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::strategy::edge_ranges;
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn set(ranges: &[(u64, u64)]) -> IntervalSet<u64> {
+        ranges.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_from_iter() {
+        assert_eq!(
+            set(&[(3, 5), (10, 14), (16, 20), (12, 18), (6, 6), (9, 2)]).ranges(),
+            &[(3, 6), (10, 20)]
+        )
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut intervals = set(&[(3, 5), (10, 14), (20, 25)]);
+        intervals.insert(6, 9);
+        assert_eq!(intervals.ranges(), &[(3, 14), (20, 25)]);
+        intervals.insert(0, 1);
+        assert_eq!(intervals.ranges(), &[(0, 1), (3, 14), (20, 25)]);
+        intervals.insert(13, 30);
+        assert_eq!(intervals.ranges(), &[(0, 1), (3, 30)]);
+        intervals.insert(40, 41);
+        assert_eq!(intervals.ranges(), &[(0, 1), (3, 30), (40, 41)]);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut intervals = set(&[(3, 14), (20, 25)]);
+        intervals.remove(5, 9);
+        assert_eq!(intervals.ranges(), &[(3, 4), (10, 14), (20, 25)]);
+        intervals.remove(12, 22);
+        assert_eq!(intervals.ranges(), &[(3, 4), (10, 11), (23, 25)]);
+        intervals.remove(0, 3);
+        assert_eq!(intervals.ranges(), &[(4, 4), (10, 11), (23, 25)]);
+        intervals.remove(15, 16);
+        assert_eq!(intervals.ranges(), &[(4, 4), (10, 11), (23, 25)]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let intervals = set(&[(3, 5), (10, 20)]);
+        assert!(!intervals.contains(2));
+        assert!(intervals.contains(3));
+        assert!(intervals.contains(5));
+        assert!(!intervals.contains(6));
+        assert!(intervals.contains(20));
+        assert!(!intervals.contains(21));
+    }
+
+    #[test]
+    fn test_union() {
+        assert_eq!(
+            set(&[(3, 5), (10, 20)]).union(&set(&[(6, 8), (15, 25)])),
+            set(&[(3, 8), (10, 25)])
+        )
+    }
+
+    #[test]
+    fn test_intersection() {
+        assert_eq!(
+            set(&[(3, 5), (10, 20)]).intersection(&set(&[(4, 12), (15, 25)])),
+            set(&[(4, 5), (10, 12), (15, 20)])
+        )
+    }
+
+    #[test]
+    fn test_difference() {
+        assert_eq!(
+            set(&[(3, 5), (10, 20)]).difference(&set(&[(4, 12), (15, 15)])),
+            set(&[(3, 3), (13, 14), (16, 20)])
+        )
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(set(&[(3, 5), (10, 20)]).len(), 14);
+        assert_eq!(IntervalSet::<u64>::new().len(), 0);
+        assert_eq!(set(&[(0, u64::MAX)]).len(), 1 << 64);
+    }
+
+    #[test]
+    fn test_edge_values() {
+        let mut intervals = set(&[(0, 0), (1, 5), (u64::MAX, u64::MAX), (10, u64::MAX - 1)]);
+        assert_eq!(intervals.ranges(), &[(0, 5), (10, u64::MAX)]);
+        intervals.insert(6, 9);
+        assert_eq!(intervals.ranges(), &[(0, u64::MAX)]);
+        intervals.remove(0, 0);
+        intervals.remove(u64::MAX, u64::MAX);
+        assert_eq!(intervals.ranges(), &[(1, u64::MAX - 1)]);
+        assert!(!intervals.contains(0));
+        assert!(!intervals.contains(u64::MAX));
+    }
+
+    #[test]
+    fn test_gaps() {
+        assert_eq!(
+            set(&[(3, 5), (10, 20), (22, 22)])
+                .gaps()
+                .collect::<Vec<(u64, u64)>>(),
+            vec![(6, 9), (21, 21)]
+        )
+    }
+
+    /// The values within 64 of either end of the domain, which covers every edge range
+    fn window() -> impl Iterator<Item = u64> {
+        (0..64).chain(u64::MAX - 63..=u64::MAX)
+    }
+
+    fn brute_force(ranges: &[(u64, u64)]) -> BTreeSet<u64> {
+        ranges
+            .iter()
+            .flat_map(|(start, end)| *start..=*end)
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn prop_from_iter_matches_brute_force(ranges in edge_ranges()) {
+            let intervals = set(&ranges);
+            let expected = brute_force(&ranges);
+            prop_assert_eq!(intervals.len(), expected.len() as u128);
+            for value in window() {
+                prop_assert_eq!(intervals.contains(value), expected.contains(&value));
+            }
+            for (start, end) in intervals.gaps() {
+                prop_assert!(start <= end);
+                prop_assert!(!expected.contains(&start) && !expected.contains(&end));
+            }
+        }
+
+        #[test]
+        fn prop_insert_remove_match_brute_force(
+            inserted in edge_ranges(),
+            removed in edge_ranges(),
+        ) {
+            let mut intervals = IntervalSet::new();
+            for (start, end) in inserted.iter() {
+                intervals.insert(*start, *end);
+            }
+            for (start, end) in removed.iter() {
+                intervals.remove(*start, *end);
+            }
+            let expected: BTreeSet<u64> = brute_force(&inserted)
+                .difference(&brute_force(&removed))
+                .copied()
+                .collect();
+            prop_assert_eq!(intervals.len(), expected.len() as u128);
+            for value in window() {
+                prop_assert_eq!(intervals.contains(value), expected.contains(&value));
+            }
+        }
+
+        #[test]
+        fn prop_set_operations_match_brute_force(a in edge_ranges(), b in edge_ranges()) {
+            let (set_a, set_b) = (set(&a), set(&b));
+            let (brute_a, brute_b) = (brute_force(&a), brute_force(&b));
+            let union = set_a.union(&set_b);
+            let intersection = set_a.intersection(&set_b);
+            let difference = set_a.difference(&set_b);
+            for value in window() {
+                let (in_a, in_b) = (brute_a.contains(&value), brute_b.contains(&value));
+                prop_assert_eq!(union.contains(value), in_a || in_b);
+                prop_assert_eq!(intersection.contains(value), in_a && in_b);
+                prop_assert_eq!(difference.contains(value), in_a && !in_b);
+            }
+        }
+    }
+}
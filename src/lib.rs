@@ -0,0 +1,101 @@
+use crate::error::ParseError;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+pub mod answers;
+pub mod automaton;
+pub mod bit_grid;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod interval_set;
+
+/// The solution to a single day's puzzle.  The raw input is parsed once into `Input`, which is
+/// then handed to each part to produce that part's answer.
+pub trait Solution {
+    type Input: Clone;
+    type Output: Display;
+
+    /// The example input from the puzzle text
+    const EXAMPLE: &'static str;
+
+    /// The example input for the second part, for days where it differs from the first part's
+    const EXAMPLE_PART2: &'static str = Self::EXAMPLE;
+
+    /// Parse the raw puzzle input
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parse the example input, for days whose example is solved with different parameters from
+    /// the real puzzle
+    fn parse_example(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+
+    /// Solve the first part of the puzzle
+    fn part1(input: Self::Input) -> Self::Output;
+
+    /// Solve the second part of the puzzle, if the day has one
+    fn part2(_input: Self::Input) -> Option<Self::Output> {
+        None
+    }
+}
+
+/// The example input for a part of a day
+pub fn example<S: Solution>(part: u8) -> &'static str {
+    if part == 2 {
+        S::EXAMPLE_PART2
+    } else {
+        S::EXAMPLE
+    }
+}
+
+/// The answer to one part of a day, along with how long it took to compute
+#[derive(Clone, PartialEq, Debug)]
+pub struct Solved {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Parse the raw input for a day and run the requested part, rendering the answer for display and
+/// timing the parse and solve separately.  `example` says whether the input is the example, so
+/// is parsed with the example's parameters.  Returns `None` if the day has no such part.
+pub fn solve<S: Solution>(
+    input: &str,
+    part: u8,
+    example: bool,
+) -> Result<Option<Solved>, ParseError> {
+    let parse_start = Instant::now();
+    let parsed = if example {
+        S::parse_example(input)?
+    } else {
+        S::parse(input)?
+    };
+    let parse_time = parse_start.elapsed();
+
+    let solve_start = Instant::now();
+    let answer = match part {
+        1 => Some(S::part1(parsed).to_string()),
+        2 => S::part2(parsed).map(|answer| answer.to_string()),
+        _ => None,
+    };
+    let solve_time = solve_start.elapsed();
+
+    Ok(answer.map(|answer| Solved {
+        answer,
+        parse_time,
+        solve_time,
+    }))
+}
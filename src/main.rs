@@ -0,0 +1,822 @@
+use advent_2025::answers::{Answers, Verdict};
+use advent_2025::day01::Day01;
+use advent_2025::day02::rule::{Rule, sum_invalid_ids};
+use advent_2025::day02::{self, Day02};
+use advent_2025::day03::Day03;
+use advent_2025::day04::{self, Day04};
+use advent_2025::day05::provenance::Provenance;
+use advent_2025::day05::report::CoverageReport;
+use advent_2025::day05::{self, Day05};
+use advent_2025::day06::Day06;
+use advent_2025::day07::Day07;
+use advent_2025::day08::Day08;
+use advent_2025::day09::Day09;
+use advent_2025::day10::Day10;
+use advent_2025::day11::Day11;
+use advent_2025::day12::Day12;
+use advent_2025::error::ParseError;
+use advent_2025::grid::Grid;
+use advent_2025::input::{InputSource, default_path, read_stdin, resolve, sha256_hex};
+use advent_2025::interval_set::IntervalSet;
+use advent_2025::{Solution, Solved, example, solve};
+use clap::{Parser, Subcommand, ValueEnum};
+use env_logger::Env;
+use log::LevelFilter;
+use serde::Serialize;
+use std::fmt;
+use std::io::{BufWriter, Write};
+use std::process::ExitCode;
+
+/// A single day that can be invoked by the runner
+struct Day {
+    day: u8,
+    parts: u8,
+    solve: fn(&str, u8, bool) -> Result<Option<Solved>, ParseError>,
+    example: fn(u8) -> &'static str,
+}
+
+const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        parts: 2,
+        solve: solve::<Day01>,
+        example: example::<Day01>,
+    },
+    Day {
+        day: 2,
+        parts: 2,
+        solve: solve::<Day02>,
+        example: example::<Day02>,
+    },
+    Day {
+        day: 3,
+        parts: 2,
+        solve: solve::<Day03>,
+        example: example::<Day03>,
+    },
+    Day {
+        day: 4,
+        parts: 2,
+        solve: solve::<Day04>,
+        example: example::<Day04>,
+    },
+    Day {
+        day: 5,
+        parts: 2,
+        solve: solve::<Day05>,
+        example: example::<Day05>,
+    },
+    Day {
+        day: 6,
+        parts: 2,
+        solve: solve::<Day06>,
+        example: example::<Day06>,
+    },
+    Day {
+        day: 7,
+        parts: 2,
+        solve: solve::<Day07>,
+        example: example::<Day07>,
+    },
+    Day {
+        day: 8,
+        parts: 2,
+        solve: solve::<Day08>,
+        example: example::<Day08>,
+    },
+    Day {
+        day: 9,
+        parts: 1,
+        solve: solve::<Day09>,
+        example: example::<Day09>,
+    },
+    Day {
+        day: 10,
+        parts: 1,
+        solve: solve::<Day10>,
+        example: example::<Day10>,
+    },
+    Day {
+        day: 11,
+        parts: 2,
+        solve: solve::<Day11>,
+        example: example::<Day11>,
+    },
+    Day {
+        day: 12,
+        parts: 1,
+        solve: solve::<Day12>,
+        example: example::<Day12>,
+    },
+];
+
+#[derive(Parser, Debug)]
+#[command(about = "Advent of Code 2025 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log solver diagnostics to stderr: `-v` for debug output, `-vv` for trace output.  Without
+    /// this flag the level is taken from `RUST_LOG`, defaulting to warnings only.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run the solver for a day, or `all` to run every solver
+    Run {
+        /// The day to run (1-12), or `all`
+        #[arg(value_parser = parse_day)]
+        day: DaySelector,
+        /// Only run this part of the day (1 or 2)
+        part: Option<u8>,
+        /// Read the puzzle input from this file instead of `input/dayNN.txt`, or `-` for stdin.
+        /// Relative paths are resolved against the crate root.
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<String>,
+        /// Run against the example input from the puzzle text
+        #[arg(long)]
+        example: bool,
+        /// Print a table of how long each part took to parse and solve.  JSON output always
+        /// includes the timings, so this only affects human-readable output.
+        #[arg(long)]
+        time: bool,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Human)]
+        format: Format,
+    },
+    /// Run solvers against the committed inputs and check their answers against `answers.toml`
+    Verify {
+        /// The day to verify (1-12), or `all`
+        #[arg(value_parser = parse_day, default_value = "all")]
+        day: DaySelector,
+    },
+    /// List every day and part that has a solver
+    List,
+    /// Sum the invalid IDs in day 2's ranges, with options that the puzzle's parts don't expose
+    Ids {
+        /// Apply the rule of this part of day 2 (1 or 2)
+        #[arg(default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Apply this rule instead of a part's: `exactly:K` or `at-least:K` for a sequence of
+        /// digits repeated K times, `palindrome`, or `unit:N` for a sequence of N digits repeated
+        /// at least twice
+        #[arg(long, conflicts_with = "part")]
+        rule: Option<Rule>,
+        /// Read the IDs, both in the input and when applying the rule, in this base (2-36)
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(2..=36))]
+        base: u32,
+        /// Read the ranges from this file instead of `input/day02.txt`, or `-` for stdin
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<String>,
+        /// Use the example ranges from the puzzle text
+        #[arg(long)]
+        example: bool,
+        /// Merge overlapping ranges before searching, so that an ID in several ranges is only
+        /// counted once
+        #[arg(long)]
+        merge: bool,
+    },
+    /// Draw the round in which each of day 4's rolls is removed, with `@` for the rolls that are
+    /// never removed
+    Peel {
+        /// Read the map from this file instead of `input/day04.txt`, or `-` for stdin
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<String>,
+        /// Use the example map from the puzzle text
+        #[arg(long)]
+        example: bool,
+        /// Also write the rounds as a heat map to this `.ppm` or `.png` file
+        #[arg(long, value_name = "PATH", value_parser = parse_image_path)]
+        image: Option<ImagePath>,
+        /// Draw each cell of the heat map as a square of this many pixels
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64))]
+        scale: u32,
+    },
+    /// Show which of day 5's fresh ranges cover each ingredient ID, by line number
+    Audit {
+        /// The IDs to look up, or every available ID in the input if there are none
+        ids: Vec<u64>,
+        /// Read the database from this file instead of `input/day05.txt`, or `-` for stdin
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<String>,
+        /// Use the example database from the puzzle text
+        #[arg(long)]
+        example: bool,
+    },
+    /// Report how day 5's fresh ranges cover the IDs between them: the gaps, the share of IDs
+    /// covered, the widths of the ranges and how many are nested inside others
+    Coverage {
+        /// Read the database from this file instead of `input/day05.txt`, or `-` for stdin
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<String>,
+        /// Use the example database from the puzzle text
+        #[arg(long)]
+        example: bool,
+        /// How to print the report
+        #[arg(long, value_enum, default_value_t = Format::Human)]
+        format: Format,
+    },
+}
+
+/// The output format of the `run` and `coverage` commands
+#[derive(Clone, Copy, PartialEq, Debug, ValueEnum)]
+enum Format {
+    /// For `run`, the bare answer for a single part or a labelled line per part.  For
+    /// `coverage`, a summary followed by tables.
+    Human,
+    /// For `run`, a JSON object per line for each part, with timings and a digest of the input.
+    /// For `coverage`, the report as a single JSON object.
+    Json,
+}
+
+/// An image file to write, in the format given by its extension
+#[derive(Clone, PartialEq, Debug)]
+struct ImagePath {
+    path: String,
+    format: ImageFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+fn parse_image_path(raw: &str) -> Result<ImagePath, String> {
+    let extension = std::path::Path::new(raw)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_ascii_lowercase());
+    let format = match extension.as_deref() {
+        Some("ppm") => ImageFormat::Ppm,
+        Some("png") => ImageFormat::Png,
+        _ => return Err(format!("expected a `.ppm` or `.png` file, got `{}`", raw)),
+    };
+    Ok(ImagePath {
+        path: raw.to_string(),
+        format,
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum DaySelector {
+    All,
+    Day(u8),
+}
+
+fn parse_day(raw: &str) -> Result<DaySelector, String> {
+    if raw == "all" {
+        Ok(DaySelector::All)
+    } else {
+        raw.parse::<u8>()
+            .map(DaySelector::Day)
+            .map_err(|_| format!("expected a day number or `all`, got `{}`", raw))
+    }
+}
+
+/// Find every (day, part) pair matching the selection, in order
+fn select_parts(day: DaySelector, part: Option<u8>) -> Vec<(&'static Day, u8)> {
+    DAYS.iter()
+        .filter(|entry| match day {
+            DaySelector::All => true,
+            DaySelector::Day(day) => entry.day == day,
+        })
+        .flat_map(|entry| (1..=entry.parts).map(move |p| (entry, p)))
+        .filter(|(_, p)| part.is_none_or(|part| *p == part))
+        .collect()
+}
+
+/// An error that prevented a part from producing an answer
+enum RunError {
+    Io(String, std::io::Error),
+    Write(String, std::io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Io(name, err) => write!(f, "error: unable to read {}: {}", name, err),
+            RunError::Write(name, err) => write!(f, "error: unable to write {}: {}", name, err),
+            RunError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// Load the input for a part from `source`, returning a name for it to use in errors along with
+/// its contents.  Standard input can only be read once, so it is read up front and passed in.
+fn load_input(
+    day: &Day,
+    part: u8,
+    source: &InputSource,
+    stdin: Option<&str>,
+) -> Result<(String, String), RunError> {
+    let read_file = |path: std::path::PathBuf| {
+        let name = path.display().to_string();
+        std::fs::read_to_string(&path)
+            .map(|contents| (name.clone(), contents))
+            .map_err(|err| RunError::Io(name, err))
+    };
+
+    match source {
+        InputSource::Default => read_file(default_path(day.day)),
+        InputSource::Path(path) => read_file(resolve(path)),
+        InputSource::Stdin => Ok(("<stdin>".to_string(), stdin.unwrap_or_default().to_string())),
+        InputSource::Example => Ok((
+            format!("day{:02} example", day.day),
+            (day.example)(part).to_string(),
+        )),
+    }
+}
+
+/// The answer to a part along with how it was computed, as emitted by `--format json`
+#[derive(Clone, PartialEq, Debug, Serialize)]
+struct Report {
+    day: u8,
+    part: u8,
+    answer: String,
+    parse_ms: f64,
+    solve_ms: f64,
+    input_sha256: String,
+}
+
+impl Report {
+    fn new(day: u8, part: u8, solved: Solved, input: &str) -> Report {
+        Report {
+            day,
+            part,
+            answer: solved.answer,
+            parse_ms: solved.parse_time.as_secs_f64() * 1000.0,
+            solve_ms: solved.solve_time.as_secs_f64() * 1000.0,
+            input_sha256: sha256_hex(input),
+        }
+    }
+}
+
+/// Pick the input source from the `--input` and `--example` arguments
+fn select_source(input: Option<String>, example: bool) -> InputSource {
+    match (input, example) {
+        (Some(arg), _) => InputSource::from_arg(&arg),
+        (None, true) => InputSource::Example,
+        (None, false) => InputSource::Default,
+    }
+}
+
+/// Read standard input up front if it is the input source
+fn read_stdin_for(source: &InputSource) -> Result<Option<String>, RunError> {
+    if *source == InputSource::Stdin {
+        read_stdin()
+            .map(Some)
+            .map_err(|err| RunError::Io("<stdin>".to_string(), err))
+    } else {
+        Ok(None)
+    }
+}
+
+fn run_part(
+    day: &Day,
+    part: u8,
+    source: &InputSource,
+    stdin: Option<&str>,
+) -> Result<Report, RunError> {
+    let (name, input) = load_input(day, part, source, stdin)?;
+    (day.solve)(&input, part, matches!(source, InputSource::Example))
+        .map(|solved| solved.expect("Day is missing a listed part"))
+        .map(|solved| Report::new(day.day, part, solved, &input))
+        .map_err(|err| RunError::Parse(err.in_input(&name)))
+}
+
+/// Sum the invalid IDs in day 2's ranges under `rule`, or the rule of `part` if there is none,
+/// optionally merging overlapping ranges first.  The sum is wide enough for the many IDs that a
+/// loose rule can match.
+fn run_ids(
+    part: u8,
+    rule: Option<Rule>,
+    base: u32,
+    source: &InputSource,
+    merge: bool,
+) -> Result<u128, RunError> {
+    let stdin = read_stdin_for(source)?;
+    let day = select_parts(DaySelector::Day(2), Some(part))[0].0;
+    let (name, input) = load_input(day, part, source, stdin.as_deref())?;
+    let ranges =
+        day02::parse_in_base(&input, base).map_err(|err| RunError::Parse(err.in_input(&name)))?;
+
+    let rule = rule.unwrap_or(match part {
+        1 => Rule::Exactly(2),
+        _ => Rule::AtLeast(2),
+    });
+    Ok(day02::prepare_ranges(ranges, merge)
+        .into_iter()
+        .map(|range| sum_invalid_ids(range, rule, base))
+        .sum())
+}
+
+/// Remove day 4's rolls, returning the round in which each roll was removed along with the rolls
+/// that are left
+fn run_peel(source: &InputSource) -> Result<(Grid<Option<u32>>, Grid<bool>), RunError> {
+    let stdin = read_stdin_for(source)?;
+    let day = select_parts(DaySelector::Day(4), Some(2))[0].0;
+    let (name, input) = load_input(day, 2, source, stdin.as_deref())?;
+    let mut map = Day04::parse(&input)
+        .map_err(|err| RunError::Parse(err.in_input(&name)))?
+        .to_grid();
+    let depths = day04::prob2::remove_rolls(&mut map);
+    Ok((depths, map))
+}
+
+/// Write the heat map of the rounds in which day 4's rolls are removed
+fn write_peel_image(
+    image: &ImagePath,
+    depths: &Grid<Option<u32>>,
+    remaining: &Grid<bool>,
+    scale: usize,
+) -> Result<(), RunError> {
+    let write = || {
+        let mut out = BufWriter::new(std::fs::File::create(&image.path)?);
+        match image.format {
+            ImageFormat::Ppm => day04::peel::write_ppm(&mut out, depths, remaining, scale)?,
+            ImageFormat::Png => day04::peel::write_png(&mut out, depths, remaining, scale)?,
+        }
+        out.flush()
+    };
+    write().map_err(|err| RunError::Write(image.path.clone(), err))
+}
+
+/// Build the coverage report for day 5's fresh ranges
+fn run_coverage(source: &InputSource) -> Result<CoverageReport, RunError> {
+    let stdin = read_stdin_for(source)?;
+    let day = select_parts(DaySelector::Day(5), Some(2))[0].0;
+    let (name, input) = load_input(day, 2, source, stdin.as_deref())?;
+    let ims = day05::parse_lines(input.lines().collect())
+        .map_err(|err| RunError::Parse(err.in_input(&name)))?;
+    Ok(CoverageReport::new(&ims.fresh, &ims.excluded))
+}
+
+/// Find the fresh ranges of day 5 covering each of `ids`, or each available ID if there are none,
+/// as lines of the ID, how many ranges cover it (its depth) and the line numbers of those ranges,
+/// noting IDs that are excluded
+fn run_audit(ids: Vec<u64>, source: &InputSource) -> Result<Vec<String>, RunError> {
+    let stdin = read_stdin_for(source)?;
+    let day = select_parts(DaySelector::Day(5), Some(1))[0].0;
+    let (name, input) = load_input(day, 1, source, stdin.as_deref())?;
+    let ims = day05::parse_lines(input.lines().collect())
+        .map_err(|err| RunError::Parse(err.in_input(&name)))?;
+
+    let provenance = Provenance::new(&ims.fresh);
+    let excluded: IntervalSet<u64> = ims.excluded.iter().copied().collect();
+    let ids = if ids.is_empty() { ims.available } else { ids };
+    Ok(ids
+        .into_iter()
+        .map(|id| {
+            let lines: Vec<String> = provenance
+                .covering(id)
+                .into_iter()
+                .map(|i| ims.fresh_lines[i].to_string())
+                .collect();
+            format!(
+                "{}: depth {}, lines [{}]{}",
+                id,
+                provenance.depth(id),
+                lines.join(", "),
+                if excluded.contains(id) {
+                    ", excluded"
+                } else {
+                    ""
+                }
+            )
+        })
+        .collect())
+}
+
+/// Format milliseconds with a fixed precision so that the timing table lines up
+fn format_ms(ms: f64) -> String {
+    format!("{:.3} ms", ms)
+}
+
+/// Print a table of the parse and solve times of every part that was run, with a total row
+fn print_timings(reports: &[Report]) {
+    println!(
+        "{:>3}  {:>4}  {:>12}  {:>12}  {:>12}",
+        "Day", "Part", "Parse", "Solve", "Total"
+    );
+    for report in reports {
+        println!(
+            "{:>3}  {:>4}  {:>12}  {:>12}  {:>12}",
+            format!("{:02}", report.day),
+            report.part,
+            format_ms(report.parse_ms),
+            format_ms(report.solve_ms),
+            format_ms(report.parse_ms + report.solve_ms)
+        );
+    }
+    let parse_total: f64 = reports.iter().map(|report| report.parse_ms).sum();
+    let solve_total: f64 = reports.iter().map(|report| report.solve_ms).sum();
+    println!(
+        "{:>3}  {:>4}  {:>12}  {:>12}  {:>12}",
+        "All",
+        "",
+        format_ms(parse_total),
+        format_ms(solve_total),
+        format_ms(parse_total + solve_total)
+    );
+}
+
+/// The log level selected by the number of `-v` flags, or `None` to defer to `RUST_LOG`
+fn verbosity_level(verbose: u8) -> Option<LevelFilter> {
+    match verbose {
+        0 => None,
+        1 => Some(LevelFilter::Debug),
+        _ => Some(LevelFilter::Trace),
+    }
+}
+
+/// Send log output to stderr so that stdout only ever holds answers
+fn init_logging(verbose: u8) {
+    let mut builder = env_logger::Builder::from_env(Env::default().default_filter_or("warn"));
+    builder.target(env_logger::Target::Stderr);
+    if let Some(level) = verbosity_level(verbose) {
+        builder.filter_level(level);
+    }
+    builder.init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            example,
+            time,
+            format,
+        } => {
+            let selected = select_parts(day, part);
+            if selected.is_empty() {
+                eprintln!("No solver found for the requested day and part");
+                return ExitCode::FAILURE;
+            }
+
+            if input.is_some() && day == DaySelector::All {
+                eprintln!("--input can only be used when running a single day");
+                return ExitCode::FAILURE;
+            }
+            let source = select_source(input, example);
+            let stdin = match read_stdin_for(&source) {
+                Ok(stdin) => stdin,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let single = selected.len() == 1;
+            let mut reports: Vec<Report> = Vec::new();
+            for (day, part) in selected {
+                match run_part(day, part, &source, stdin.as_deref()) {
+                    Ok(report) => {
+                        match format {
+                            Format::Human if single => println!("{}", report.answer),
+                            Format::Human => {
+                                println!("Day {:02} part {}: {}", day.day, part, report.answer)
+                            }
+                            Format::Json => println!(
+                                "{}",
+                                serde_json::to_string(&report).expect("reports serialize")
+                            ),
+                        }
+                        reports.push(report);
+                    }
+                    Err(err) => {
+                        eprintln!("{}", err);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+
+            if time && format == Format::Human {
+                println!();
+                print_timings(&reports);
+            }
+        }
+        Command::Verify { day } => {
+            let answers = match Answers::load(&Answers::default_path()) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let (mut correct, mut wrong, mut unknown) = (0, 0, 0);
+            for (day, part) in select_parts(day, None) {
+                let label = format!("Day {:02} part {}", day.day, part);
+                match run_part(day, part, &InputSource::Default, None) {
+                    Ok(Report { answer, .. }) => match answers.check(day.day, part, &answer) {
+                        Verdict::Correct => {
+                            correct += 1;
+                            println!("{}: ok", label);
+                        }
+                        Verdict::Wrong { expected } => {
+                            wrong += 1;
+                            println!("{}: WRONG, got {} but expected {}", label, answer, expected);
+                        }
+                        Verdict::Unknown => {
+                            unknown += 1;
+                            println!("{}: no known answer, got {}", label, answer);
+                        }
+                    },
+                    Err(err) => {
+                        wrong += 1;
+                        println!("{}: FAILED", label);
+                        eprintln!("{}", err);
+                    }
+                }
+            }
+
+            println!(
+                "{} correct, {} wrong, {} without a known answer",
+                correct, wrong, unknown
+            );
+            if wrong > 0 {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Ids {
+            part,
+            rule,
+            base,
+            input,
+            example,
+            merge,
+        } => {
+            let source = select_source(input, example);
+            match run_ids(part, rule, base, &source, merge) {
+                Ok(sum) => println!("{}", sum),
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Peel {
+            input,
+            example,
+            image,
+            scale,
+        } => {
+            let source = select_source(input, example);
+            let result = run_peel(&source).and_then(|(depths, remaining)| {
+                print!("{}", day04::peel::render(&depths, &remaining));
+                match image {
+                    Some(image) => write_peel_image(&image, &depths, &remaining, scale as usize),
+                    None => Ok(()),
+                }
+            });
+            if let Err(err) = result {
+                eprintln!("{}", err);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Audit {
+            ids,
+            input,
+            example,
+        } => {
+            let source = select_source(input, example);
+            match run_audit(ids, &source) {
+                Ok(lines) => {
+                    for line in lines {
+                        println!("{}", line);
+                    }
+                }
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Coverage {
+            input,
+            example,
+            format,
+        } => {
+            let source = select_source(input, example);
+            match run_coverage(&source) {
+                Ok(report) => match format {
+                    Format::Human => print!("{}", report),
+                    Format::Json => println!(
+                        "{}",
+                        serde_json::to_string(&report).expect("report serializes")
+                    ),
+                },
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::List => {
+            for (day, part) in select_parts(DaySelector::All, None) {
+                println!("Day {:02} part {}", day.day, part);
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day("all"), Ok(DaySelector::All));
+        assert_eq!(parse_day("8"), Ok(DaySelector::Day(8)));
+        assert!(parse_day("eight").is_err());
+    }
+
+    #[test]
+    fn test_parse_image_path() {
+        assert_eq!(
+            parse_image_path("out/peel.PNG"),
+            Ok(ImagePath {
+                path: "out/peel.PNG".to_string(),
+                format: ImageFormat::Png
+            })
+        );
+        assert_eq!(
+            parse_image_path("peel.ppm").map(|image| image.format),
+            Ok(ImageFormat::Ppm)
+        );
+        assert!(parse_image_path("peel.jpg").is_err());
+        assert!(parse_image_path("peel").is_err());
+    }
+
+    #[test]
+    fn test_run_audit() {
+        assert_eq!(
+            run_audit(vec![17, 8], &InputSource::Example).ok(),
+            Some(vec![
+                "17: depth 2, lines [3, 4]".to_string(),
+                "8: depth 0, lines []".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_run_coverage() {
+        let report = run_coverage(&InputSource::Example).ok().unwrap();
+        assert_eq!(report.gaps, vec![(6, 9)]);
+        assert_eq!(report.covered, 14);
+    }
+
+    #[test]
+    fn test_verbosity_level() {
+        assert_eq!(verbosity_level(0), None);
+        assert_eq!(verbosity_level(1), Some(LevelFilter::Debug));
+        assert_eq!(verbosity_level(3), Some(LevelFilter::Trace));
+    }
+
+    #[test]
+    fn test_format_ms() {
+        assert_eq!(format_ms(1.5), "1.500 ms");
+    }
+
+    #[test]
+    fn test_report_json() {
+        let report = Report::new(
+            1,
+            2,
+            Solved {
+                answer: "6".to_string(),
+                parse_time: std::time::Duration::from_micros(250),
+                solve_time: std::time::Duration::from_millis(2),
+            },
+            "abc",
+        );
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            "{\"day\":1,\"part\":2,\"answer\":\"6\",\"parse_ms\":0.25,\"solve_ms\":2.0,\
+             \"input_sha256\":\"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad\"}"
+        );
+    }
+
+    #[test]
+    fn test_select_parts() {
+        assert_eq!(
+            select_parts(DaySelector::Day(8), Some(2))
+                .into_iter()
+                .map(|(day, part)| (day.day, part))
+                .collect::<Vec<(u8, u8)>>(),
+            vec![(8, 2)]
+        );
+        assert_eq!(select_parts(DaySelector::Day(9), None).len(), 1);
+        assert_eq!(select_parts(DaySelector::All, None).len(), 21);
+    }
+}